@@ -0,0 +1,609 @@
+//! `cheat`: list, show, run and search the cheat sheet sections.
+//!
+//! ```text
+//! cheat list
+//! cheat show ownership
+//! cheat run vec
+//! cheat try ownership
+//! cheat search borrow
+//! cheat check
+//! cheat explain E0382
+//! cheat quiz OWNERSHIP
+//! cheat review
+//! cheat exercise new lifetimes && cd lifetimes && cheat exercise check
+//! cheat export --format markdown > cheat-sheet.md
+//! cheat export --format json > cheat-sheet.json
+//! cheat build-site out/
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cheat_sheet::annotations;
+use cheat_sheet::exercise::{self, Outcome};
+use cheat_sheet::explain::{self, Explanation};
+use cheat_sheet::export::{json, markdown, site};
+use cheat_sheet::outline;
+use cheat_sheet::progress::{self, Progress};
+use cheat_sheet::quiz::{self, Kind, Tally};
+use cheat_sheet::registry::{self, Section};
+use cheat_sheet::runner::{self, Diff, Limits, Run};
+use cheat_sheet::search;
+use cheat_sheet::{compile_fail, snapshot};
+
+type Result<T, E = Box<dyn Error>> = std::result::Result<T, E>;
+
+const USAGE: &str = "\
+usage: cheat <command> [args]
+
+commands:
+  list              list every section with its tags
+  show <section>    print the source of a section, comments included
+  run <section>     run a section's demo
+  try <section> [--no-edit] [--timeout SECS]
+                    edit a section in $EDITOR, then compile, run and diff it
+                    (no isolation: it runs as you; only time and output are capped)
+  search <query>    rank sections by words in their titles, code and comments
+                    (typos and prefixes match too)
+  check [section]   run sections and verify their `//=>` output annotations
+  explain [E####]   explain a compiler error with the sections that show it
+  quiz [--seed N] [-n COUNT] [section|tag]
+                    answer questions generated from the demos, graded per tag
+  review            list the sections due for review, most missed first
+  progress [path|export|merge <file>]
+                    where quiz progress is kept; print it; merge another copy
+  exercise list|new <section> [dir]|check [dir]
+                    scaffold a helper to reimplement; grade it with hidden tests
+  export [--format markdown|json]
+                    print both sheets as Markdown, or as JSON with source spans
+  build-site <dir>  write a static HTML site (no network needed to view it)
+
+<section> is an ID from `cheat list` (e.g. `ownership`, `types/vec`) or a tag.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match dispatch(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("cheat: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn dispatch(args: &[&str]) -> Result<()> {
+    match args {
+        ["list"] => list(),
+        ["show", query] => show(section(query)?),
+        ["run", query] => run(section(query)?),
+        ["try", query, options @ ..] => try_section(section(query)?, options),
+        ["search", terms @ ..] if !terms.is_empty() => search(&terms.join(" ")),
+        ["check"] => check(registry::registry().iter()),
+        ["check", query] => check([section(query)?]),
+        ["explain"] => {
+            for e in explain::explanations() {
+                println!("{}  {}", e.code, e.title);
+            }
+            Ok(())
+        }
+        ["explain", code] => explain(
+            explain::find(code)
+                .ok_or_else(|| format!("no explanation for `{code}` (see `cheat explain`)"))?,
+        ),
+        ["quiz", rest @ ..] => quiz(rest),
+        ["review"] => review(),
+        ["progress"] | ["progress", "path"] => {
+            println!("{}", progress_path()?.display());
+            Ok(())
+        }
+        ["progress", "export"] => {
+            print!("{}", Progress::load(&progress_path()?)?);
+            Ok(())
+        }
+        ["progress", "merge", file] => merge_progress(file),
+        ["exercise", "list"] => {
+            for e in exercise::exercises() {
+                println!("{:<20}  {}", e.section, e.task);
+            }
+            Ok(())
+        }
+        ["exercise", "new", query] => new_exercise(query, None),
+        ["exercise", "new", query, dir] => new_exercise(query, Some(dir)),
+        ["exercise", "check"] => check_exercise(Path::new(".")),
+        ["exercise", "check", dir] => check_exercise(Path::new(dir)),
+        ["export"] | ["export", "--format", "markdown" | "md"] => {
+            print!("{}", markdown::render());
+            Ok(())
+        }
+        ["export", "--format", "json"] => {
+            println!("{}", json::render());
+            Ok(())
+        }
+        ["export", "--format", other] => Err(format!("unknown export format `{other}`").into()),
+        ["build-site", dir] => {
+            let written = site::build(dir.as_ref())?;
+            println!("wrote {written} files to {dir}");
+            Ok(())
+        }
+        ["help" | "-h" | "--help"] => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn section(query: &str) -> Result<&'static Section> {
+    registry::resolve(query)
+        .ok_or_else(|| format!("no section matches `{query}` (see `cheat list`)").into())
+}
+
+fn list() -> Result<()> {
+    let width = registry::registry()
+        .iter()
+        .map(|s| s.id.len())
+        .max()
+        .unwrap_or(0);
+    let mut sheet = None;
+    for s in registry::registry() {
+        if sheet != Some(s.sheet) {
+            if sheet.is_some() {
+                println!();
+            }
+            println!("{}", s.sheet);
+            sheet = Some(s.sheet);
+        }
+        println!(
+            "{:>4}  {:<width$}  {}  [{}]",
+            s.number,
+            s.id,
+            s.title,
+            s.tags.join(", ")
+        );
+    }
+    Ok(())
+}
+
+fn show(s: &'static Section) -> Result<()> {
+    println!("// {} #{}: {} ({})", s.sheet, s.number, s.title, s.file);
+    print!("{}", s.source);
+    let shared = outline::shared_items();
+    let used = outline::outline(s).uses(&shared);
+    if !used.is_empty() {
+        println!("\n// from {}:", registry::SHARED_FILE);
+        for item in used {
+            println!("\n{}", item.source);
+        }
+    }
+    Ok(())
+}
+
+fn run(s: &Section) -> Result<()> {
+    (s.run)();
+    Ok(())
+}
+
+fn search(query: &str) -> Result<()> {
+    let hits = search::search(query);
+    if hits.is_empty() {
+        return Err(format!("nothing mentions `{query}`").into());
+    }
+    for hit in hits {
+        let s = hit.section;
+        println!("{}  {}  [{}]", s.id, s.title, s.tags.join(", "));
+        if !hit.items.is_empty() {
+            println!("  defines: {}", hit.items.join(", "));
+        }
+        for (n, line) in hit.lines {
+            println!("  {n:>3}: {}", line.trim());
+        }
+    }
+    Ok(())
+}
+
+/// Runs `section` in a child `cheat run` and returns what it printed.
+fn capture(section: &Section) -> Result<String> {
+    let out = Command::new(std::env::current_exe()?)
+        .args(["run", section.id])
+        .output()?;
+    if !out.status.success() {
+        return Err(format!(
+            "`{}` failed: {}",
+            section.id,
+            String::from_utf8_lossy(&out.stderr).trim()
+        )
+        .into());
+    }
+    Ok(String::from_utf8(out.stdout)?)
+}
+
+fn check<'a>(sections: impl IntoIterator<Item = &'a Section>) -> Result<()> {
+    let (mut passed, mut failed) = (0, 0);
+    for s in sections {
+        match annotations::check(s, &capture(s)?) {
+            Ok(n) => passed += n,
+            Err(misses) => {
+                failed += misses.len();
+                for miss in misses {
+                    eprintln!("{miss}");
+                }
+            }
+        }
+    }
+    println!("{passed} annotations ok, {failed} failed");
+    if failed > 0 {
+        return Err(format!("{failed} annotations did not match").into());
+    }
+    Ok(())
+}
+
+fn explain(e: &Explanation) -> Result<()> {
+    println!("{}: {}\n", e.code, e.title);
+    for demo in e.demos() {
+        let s = demo.section;
+        println!("{}  {}  ({}:{})", s.id, s.title, s.file, demo.line);
+        let lines: Vec<&str> = s.source.lines().collect();
+        let first = demo.line.saturating_sub(2).max(1);
+        let last = (demo.line + 2).min(lines.len());
+        for n in first..=last {
+            let mark = if n == demo.line { '>' } else { ' ' };
+            println!("{}", format!("{mark} {n:>3}: {}", lines[n - 1]).trim_end());
+        }
+        println!();
+    }
+    println!("fix: {}\n", e.fix);
+    println!("fails with {}:\n", e.code);
+    print_indented(e.failing);
+    println!("\ncompiles:\n");
+    print_indented(e.passing);
+    Ok(())
+}
+
+fn print_indented(code: &str) {
+    for line in code.lines() {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("    {line}");
+        }
+    }
+}
+
+fn quiz(mut args: &[&str]) -> Result<()> {
+    let mut seed = None;
+    let mut count = 10;
+    let mut sections: Vec<&'static Section> = registry::registry().iter().collect();
+    loop {
+        match args {
+            ["--seed", n, rest @ ..] => {
+                seed = Some(
+                    n.parse()
+                        .map_err(|_| format!("--seed takes a number, not `{n}`"))?,
+                );
+                args = rest;
+            }
+            ["-n", n, rest @ ..] => {
+                count = n
+                    .parse()
+                    .map_err(|_| format!("-n takes a number, not `{n}`"))?;
+                args = rest;
+            }
+            [flag @ ("--seed" | "-n")] => return Err(format!("{flag} takes a number").into()),
+            [flag, ..] if flag.starts_with('-') => {
+                return Err(format!("unknown option `{flag}`").into())
+            }
+            [query, rest @ ..] => {
+                sections = match registry::find(query) {
+                    Some(s) => vec![s],
+                    None => registry::with_tag(query).collect(),
+                };
+                if sections.is_empty() {
+                    return Err(format!("no section or tag matches `{query}`").into());
+                }
+                args = rest;
+            }
+            [] => break,
+        }
+    }
+    let seed = match seed {
+        Some(seed) => seed,
+        None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64,
+    };
+
+    let mut questions = quiz::questions(sections);
+    quiz::shuffle(&mut questions, seed);
+    questions.truncate(count);
+
+    let mut replies = io::stdin().lock().lines();
+    let mut outputs = HashMap::new();
+    let mut tally = Tally::default();
+    for (i, q) in questions.iter().enumerate() {
+        println!(
+            "[{}/{}] {}: {}\n",
+            i + 1,
+            questions.len(),
+            q.section.id,
+            q.prompt()
+        );
+        print_indented(&q.code);
+        print!("\n> ");
+        io::stdout().flush()?;
+        let Some(reply) = replies.next().transpose()? else {
+            println!();
+            break;
+        };
+        if let Kind::Prints(_) = q.kind {
+            // The answer key is the annotation; make sure it is still what
+            // the section really prints.
+            if !outputs.contains_key(q.section.id) {
+                outputs.insert(q.section.id, capture(q.section)?);
+            }
+            if annotations::check(q.section, &outputs[q.section.id]).is_err() {
+                return Err(format!(
+                    "`{}` no longer prints what its annotations say (see `cheat check`)",
+                    q.section.id
+                )
+                .into());
+            }
+        }
+        let grade = q.grade(&reply)?;
+        tally.record(q.section, grade.correct);
+        if grade.correct {
+            println!("right\n");
+        } else {
+            println!("wrong: {}\n", grade.answer);
+        }
+    }
+    println!("{tally}");
+
+    if tally.total > 0 {
+        let path = progress_path()?;
+        let mut progress = Progress::load(&path)?;
+        let today = progress::today();
+        for (id, (right, total)) in &tally.by_section {
+            progress.record(id, *right as u32, *total as u32, today);
+        }
+        progress.save(&path)?;
+    }
+    Ok(())
+}
+
+/// `$CHEAT_PROGRESS`, or `cheat/progress.tsv` under the XDG data directory.
+fn progress_path() -> Result<PathBuf> {
+    if let Some(path) = std::env::var_os("CHEAT_PROGRESS") {
+        return Ok(path.into());
+    }
+    let data = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+        .ok_or("can't find a data directory: set XDG_DATA_HOME or CHEAT_PROGRESS")?;
+    Ok(data.join("cheat").join("progress.tsv"))
+}
+
+fn review() -> Result<()> {
+    let progress = Progress::load(&progress_path()?)?;
+    let today = progress::today();
+    // Cards of renamed or removed sections (e.g. merged from an older
+    // checkout) can't be reviewed; say so instead of scheduling them.
+    let (known, unknown): (Vec<_>, Vec<_>) = progress
+        .cards
+        .iter()
+        .partition(|(id, _)| registry::find(id).is_some());
+    if !unknown.is_empty() {
+        let ids: Vec<_> = unknown.iter().map(|(id, _)| id.as_str()).collect();
+        eprintln!(
+            "cheat: ignoring unknown section(s) in progress: {}",
+            ids.join(", ")
+        );
+    }
+    let due: Vec<_> = progress
+        .due(today)
+        .into_iter()
+        .filter_map(|(id, card)| Some((registry::find(id)?, card)))
+        .collect();
+    if due.is_empty() {
+        match known.iter().map(|(_, c)| c.due).min() {
+            Some(next) => println!(
+                "nothing due; next review in {} day(s)",
+                next.saturating_sub(today)
+            ),
+            None => println!("no quiz results yet (run `cheat quiz`)"),
+        }
+        return Ok(());
+    }
+
+    println!("due for review, most missed first:\n");
+    let width = due.iter().map(|(s, _)| s.id.len()).max().unwrap_or(0);
+    for (s, card) in due {
+        println!(
+            "  {:<width$}  {}  ({}/{} right, ease {}.{:02})",
+            s.id,
+            s.title,
+            card.right,
+            u64::from(card.right) + u64::from(card.wrong),
+            card.ease / 100,
+            card.ease % 100
+        );
+        let items: Vec<String> = outline::outline(s)
+            .items
+            .iter()
+            .map(|i| i.label())
+            .collect();
+        if !items.is_empty() {
+            println!("  {:<width$}  {}", "", items.join(", "));
+        }
+    }
+    println!("\nquiz one with `cheat quiz <section>`");
+    Ok(())
+}
+
+fn merge_progress(file: &str) -> Result<()> {
+    let text = std::fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?;
+    let theirs = Progress::parse(&text).map_err(|e| format!("{file}: {e}"))?;
+    let path = progress_path()?;
+    let mut progress = Progress::load(&path)?;
+    let count = theirs.cards.len();
+    progress.merge(theirs);
+    progress.save(&path)?;
+    println!(
+        "merged {count} sections from {file} into {}",
+        path.display()
+    );
+    Ok(())
+}
+
+fn new_exercise(query: &str, dir: Option<&str>) -> Result<()> {
+    let e = exercise::find(query)
+        .ok_or_else(|| format!("no exercise for `{query}` (see `cheat exercise list`)"))?;
+    let dir = dir.map_or_else(|| PathBuf::from(e.reference().module()), PathBuf::from);
+    exercise::scaffold(e, &dir)?;
+    println!("{}: {}", e.section, e.task);
+    println!(
+        "wrote {}; fill in the todo!()s, then run `cheat exercise check` there",
+        dir.join("src/lib.rs").display()
+    );
+    Ok(())
+}
+
+fn check_exercise(dir: &Path) -> Result<()> {
+    let e = exercise::scaffolded(dir)?;
+    let lib = std::fs::read_to_string(dir.join("src/lib.rs"))?;
+    let report = exercise::check(e, &lib)?;
+    println!("{}: {}\n", e.section, e.task);
+    if let Some(errors) = &report.compile_errors {
+        println!("does not compile:");
+        print_indented(errors.trim_end());
+        println!();
+    }
+    for (case, outcome) in &report.cases {
+        match outcome {
+            Outcome::Passed => println!("  ok    {}", case.name),
+            Outcome::Failed | Outcome::NotRun => {
+                let label = if *outcome == Outcome::Failed {
+                    "FAIL"
+                } else {
+                    "----"
+                };
+                println!("  {label}  {}", case.name);
+                println!("        hint: {}", case.hint);
+            }
+        }
+    }
+    println!(
+        "\n{}/{} passed; reference: `cheat show {}` ({})",
+        report.passed(),
+        report.cases.len(),
+        e.section,
+        e.reference_location()
+    );
+    if !report.all_passed() {
+        return Err(format!(
+            "{} of {} cases failed",
+            report.cases.len() - report.passed(),
+            report.cases.len()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn try_section(s: &Section, mut options: &[&str]) -> Result<()> {
+    if s.needs_cargo() {
+        return Err(format!(
+            "`{}` uses crates.io crates (features: {}), so it can't be built with bare rustc",
+            s.id,
+            s.features.join(", ")
+        )
+        .into());
+    }
+    let mut edit = true;
+    let mut limits = Limits::default();
+    loop {
+        match options {
+            ["--no-edit", rest @ ..] => {
+                edit = false;
+                options = rest;
+            }
+            ["--timeout", secs, rest @ ..] => {
+                // Negative, NaN and infinite seconds are rejected here too.
+                limits.timeout = secs
+                    .parse()
+                    .ok()
+                    .and_then(|secs| std::time::Duration::try_from_secs_f64(secs).ok())
+                    .ok_or_else(|| format!("--timeout takes seconds, not `{secs}`"))?;
+                options = rest;
+            }
+            [] => break,
+            [other, ..] => return Err(format!("unknown option `{other}`").into()),
+        }
+    }
+
+    let dir = std::env::temp_dir().join(format!("cheat-try-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let file = dir.join(format!("{}.rs", s.module()));
+    std::fs::write(&file, compile_fail::standalone(s))?;
+    let edited = if edit { open_editor(&file) } else { Ok(()) };
+    let source = edited.and_then(|()| Ok(std::fs::read_to_string(&file)?));
+    let _ = std::fs::remove_dir_all(&dir);
+    let source = source?;
+
+    let output = match runner::build_and_run(&source, limits)? {
+        Run::CompileError(errors) => {
+            eprint!("{errors}");
+            return Err(format!("your `{}` does not compile", s.id).into());
+        }
+        Run::Ran(output) => output,
+    };
+    print!("{}", output.stdout);
+    eprint!("{}", output.stderr);
+    if output.truncated {
+        println!("[output cut at {} bytes]", limits.max_output);
+    }
+
+    let unordered = s.unordered_output();
+    let original = snapshot::normalize(s.output, unordered);
+    let yours = snapshot::normalize(&output.stdout, unordered);
+    let changes = runner::diff(&original, &yours);
+    if changes.iter().all(|d| matches!(d, Diff::Same(_))) {
+        println!("\noutput matches the original `{}`", s.id);
+    } else {
+        println!(
+            "\ndiff against the original `{}` (- original, + yours):",
+            s.id
+        );
+        for line in changes {
+            println!("{line}");
+        }
+    }
+
+    match output.status {
+        None => Err(format!("stopped after {:?}", limits.timeout).into()),
+        Some(status) if !status.success() => Err(format!("program exited with {status}").into()),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Opens `file` in `$VISUAL` or `$EDITOR` (which may carry arguments, like
+/// `code --wait`) and waits for it to close.
+fn open_editor(file: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut words = editor.split_whitespace();
+    let program = words.next().ok_or("$EDITOR is empty")?;
+    let status = Command::new(program)
+        .args(words)
+        .arg(file)
+        .status()
+        .map_err(|e| format!("can't start `{editor}`: {e}"))?;
+    if !status.success() {
+        return Err(format!("`{editor}` exited with {status}").into());
+    }
+    Ok(())
+}
@@ -0,0 +1,389 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn cheat(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cheat"))
+        .args(args)
+        .output()
+        .expect("run cheat")
+}
+
+fn stdout(args: &[&str]) -> String {
+    let out = cheat(args);
+    assert!(
+        out.status.success(),
+        "cheat {args:?}: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8(out.stdout).unwrap()
+}
+
+#[test]
+fn list_shows_both_sheets_with_tags() {
+    let out = stdout(&["list"]);
+    assert!(out.contains("basics\n"));
+    assert!(out.contains("types\n"));
+    assert!(out.contains("ownership"));
+    assert!(out.contains("[OWNERSHIP, BORROWING]"));
+    assert!(out.contains("types/hashmap"));
+}
+
+#[test]
+fn show_prints_source_with_comments() {
+    let out = stdout(&["show", "ownership"]);
+    assert!(out.contains("// takes_ownership(s);       // would move `s`"));
+    assert!(out.contains("pub fn borrow_str"));
+
+    let out = stdout(&["show", "traits"]);
+    assert!(out.contains("use crate::shared::{id, Speak};"));
+    assert!(
+        out.contains("\n// from crates/cheat_sheet/src/shared.rs:\n\npub fn id<T>(x: T) -> T {"),
+        "{out}"
+    );
+    assert!(out.contains("impl Speak for i32 {"));
+}
+
+#[test]
+fn run_executes_only_that_section() {
+    let out = stdout(&["run", "vec"]);
+    assert_eq!(
+        out,
+        "nums=[1, 2, 3, 4], first=1, maybe_first=Some(1)\n1 2 3 4 \nnums after +10: [11, 12, 13, 14]\n"
+    );
+}
+
+fn try_with_editor(editor: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cheat"))
+        .arg("try")
+        .args(args)
+        .env_remove("VISUAL")
+        .env("EDITOR", editor)
+        .output()
+        .expect("run cheat")
+}
+
+#[test]
+fn try_runs_the_edited_section_and_diffs_it() {
+    let out = try_with_editor("sed -i s/yo/ya/", &["ownership"]);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let out = String::from_utf8(out.stdout).unwrap();
+    assert!(out.contains("after borrow_mut: ya!\n"), "{out}");
+    assert!(
+        out.contains("\n- after borrow_mut: yo!\n+ after borrow_mut: ya!\n"),
+        "{out}"
+    );
+
+    let out = stdout(&["try", "vec", "--no-edit"]);
+    assert!(
+        out.ends_with("\noutput matches the original `vec`\n"),
+        "{out}"
+    );
+}
+
+#[test]
+fn try_reports_compile_errors_and_timeouts() {
+    let out = try_with_editor("sed -i s/123i32/1u8+1i32/", &["ownership"]);
+    assert!(!out.status.success());
+    let err = String::from_utf8_lossy(&out.stderr);
+    assert!(err.contains("E0308"), "{err}");
+    assert!(err.contains("does not compile"), "{err}");
+
+    let spin = "sed -i s/run();/loop{}/";
+    let out = try_with_editor(spin, &["vec", "--timeout", "0.5"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("stopped after 500ms"));
+    for bad in ["-1", "nan", "inf", "soon"] {
+        let out = try_with_editor("true", &["vec", "--timeout", bad]);
+        assert_eq!(out.status.code(), Some(1), "{bad}");
+        let err = String::from_utf8_lossy(&out.stderr);
+        assert!(
+            err.contains(&format!("--timeout takes seconds, not `{bad}`")),
+            "{err}"
+        );
+    }
+
+    let out = try_with_editor("true", &["types/serialization"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("can't be built with bare rustc"));
+}
+
+#[test]
+fn search_finds_every_mention() {
+    let out = stdout(&["search", "borrow"]);
+    for id in ["ownership", "types/strings", "types/references"] {
+        assert!(
+            out.lines().any(|l| l.starts_with(id)),
+            "{id} missing:\n{out}"
+        );
+    }
+    assert!(!out.lines().any(|l| l.starts_with("patterns")));
+}
+
+#[test]
+fn search_ranks_split_and_misspelled_words() {
+    let out = stdout(&["search", "or", "insert"]);
+    let ids: Vec<&str> = out
+        .lines()
+        .filter(|l| !l.starts_with(' '))
+        .filter_map(|l| l.split_whitespace().next())
+        .collect();
+    assert!(
+        ids[..2].contains(&"hashmap") && ids[..2].contains(&"types/hashmap"),
+        "{out}"
+    );
+    assert!(out.contains(".or_insert(2);"));
+
+    let out = stdout(&["search", "lifetim"]);
+    assert!(out.starts_with("lifetimes  Lifetimes (minimal)"), "{out}");
+    assert!(out.contains("  defines: fn pick_longer\n"), "{out}");
+
+    let out = cheat(&["search", "zzzz"]);
+    assert!(!out.status.success());
+}
+
+#[test]
+fn unknown_section_is_an_error() {
+    let out = cheat(&["run", "nope"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("no section matches `nope`"));
+}
+
+#[test]
+fn check_verifies_annotations() {
+    let out = stdout(&["check"]);
+    assert!(out.ends_with(" annotations ok, 0 failed\n"), "{out}");
+    assert_eq!(
+        stdout(&["check", "functions"]),
+        "1 annotations ok, 0 failed\n"
+    );
+}
+
+#[test]
+fn explain_points_at_the_demo() {
+    let out = stdout(&["explain", "E0382"]);
+    assert!(out.starts_with("E0382: use of moved value\n"), "{out}");
+    assert!(out.contains("ownership  Ownership"), "{out}");
+    assert!(out.contains(">  22:     let v2 = v1;"), "{out}");
+    assert!(out.contains("\nfails with E0382:\n"));
+    assert!(out.contains("let v2 = v1.clone();"));
+
+    let out = stdout(&["explain", "e0106"]);
+    assert!(out.contains("pub fn pick_longer<'a>"), "{out}");
+    // Blank lines in the examples aren't indented.
+    assert!(out.contains("\n\n"), "{out}");
+    assert!(out.lines().all(|l| !l.ends_with(' ')), "{out}");
+    assert!(stdout(&["explain"]).contains("E0499  cannot borrow"));
+    assert!(!cheat(&["explain", "E9999"]).status.success());
+}
+
+/// A progress file of the test's own under the temp dir.
+fn progress_file(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("cheat-{name}-{}.tsv", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn quiz(progress: &std::path::Path, args: &[&str], answers: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cheat"))
+        .arg("quiz")
+        .args(args)
+        .env("CHEAT_PROGRESS", progress)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("run cheat");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(answers.as_bytes())
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(out.status.success());
+    String::from_utf8(out.stdout).unwrap()
+}
+
+fn with_progress(progress: &std::path::Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_cheat"))
+        .args(args)
+        .env("CHEAT_PROGRESS", progress)
+        .output()
+        .expect("run cheat");
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8(out.stdout).unwrap()
+}
+
+#[test]
+fn quiz_grades_answers_per_tag() {
+    let progress = progress_file("quiz");
+    let answers = "tuple destructure: p=1, q=2\n4 or 5\nif let got 6\n";
+    let out = quiz(&progress, &["--seed", "1", "-n", "3", "patterns"], answers);
+    assert!(
+        out.contains("[1/3] patterns: What does the marked line print?"),
+        "{out}"
+    );
+    assert!(out.contains("wrong: if let got 5"), "{out}");
+    assert!(
+        out.ends_with("MATCH     2/3\nPATTERNS  2/3\ntotal     2/3\n"),
+        "{out}"
+    );
+    let saved = std::fs::read_to_string(&progress).unwrap();
+    assert!(saved.starts_with("cheat-progress 1\n"), "{saved}");
+    assert!(saved.contains("\npatterns\t1\t1\t236\t"), "{saved}");
+    let _ = std::fs::remove_file(progress);
+}
+
+#[test]
+fn quiz_rejects_unknown_options() {
+    for (args, message) in [
+        (&["--sed", "1"][..], "cheat: unknown option `--sed`\n"),
+        (&["patterns", "-x"], "cheat: unknown option `-x`\n"),
+        (&["-n"], "cheat: -n takes a number\n"),
+    ] {
+        let out = cheat(&[&["quiz"][..], args].concat());
+        assert_eq!(out.status.code(), Some(1), "{args:?}");
+        assert_eq!(String::from_utf8_lossy(&out.stderr), message);
+        assert!(out.stdout.is_empty());
+    }
+}
+
+#[test]
+fn review_lists_missed_sections_and_progress_merges() {
+    let laptop = progress_file("laptop");
+    let out = with_progress(&laptop, &["review"]);
+    assert_eq!(out, "no quiz results yet (run `cheat quiz`)\n");
+    quiz(&laptop, &["--seed", "1", "-n", "1", "lifetimes"], "short\n");
+    let out = with_progress(&laptop, &["review"]);
+    assert!(
+        out.starts_with("nothing due; next review in 1 day(s)"),
+        "{out}"
+    );
+
+    // A file from another machine, with `result` missed and overdue.
+    let desktop = progress_file("desktop");
+    std::fs::write(
+        &desktop,
+        "cheat-progress 1\nresult\t0\t1\t196\t0\t1\t3\t0\n",
+    )
+    .unwrap();
+    let out = with_progress(&laptop, &["progress", "merge", desktop.to_str().unwrap()]);
+    assert!(out.starts_with("merged 1 sections from "), "{out}");
+    let out = with_progress(&laptop, &["review"]);
+    assert!(out.contains("  result  Option + Result  "), "{out}");
+    assert!(out.contains("(1/4 right, ease 1.96)"), "{out}");
+    assert!(out.contains("fn wrapper_using_q"), "{out}");
+    let exported = with_progress(&laptop, &["progress", "export"]);
+    assert!(exported.contains("\nlifetimes\t0\t1\t196\t"), "{exported}");
+    assert!(exported.contains("\nresult\t"), "{exported}");
+
+    // An overdue card for a section that no longer exists is skipped.
+    let stale = progress_file("stale");
+    std::fs::write(
+        &stale,
+        "cheat-progress 1\n# c\nold_section\t0\t1\t250\t100\t0\t1\t99\n",
+    )
+    .unwrap();
+    let out = Command::new(env!("CARGO_BIN_EXE_cheat"))
+        .arg("review")
+        .env("CHEAT_PROGRESS", &stale)
+        .output()
+        .expect("run cheat");
+    assert!(out.status.success(), "{out:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&out.stdout),
+        "no quiz results yet (run `cheat quiz`)\n"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&out.stderr),
+        "cheat: ignoring unknown section(s) in progress: old_section\n"
+    );
+    let _ = std::fs::remove_file(stale);
+
+    std::fs::write(&desktop, "cheat-progress 9\n").unwrap();
+    let out = cheat(&["progress", "merge", desktop.to_str().unwrap()]);
+    assert!(String::from_utf8_lossy(&out.stderr).contains("version 9 is not supported"));
+    let _ = std::fs::remove_file(laptop);
+    let _ = std::fs::remove_file(desktop);
+}
+
+#[test]
+fn exercise_scaffold_and_check() {
+    let dir = std::env::temp_dir().join(format!("cheat-exercise-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let out = stdout(&["exercise", "new", "result", dir.to_str().unwrap()]);
+    assert!(
+        out.starts_with("result: make `maybe_pos` return None"),
+        "{out}"
+    );
+    let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("todo!()"), "{lib}");
+    assert!(dir.join("Cargo.toml").exists());
+    assert!(
+        !cheat(&["exercise", "new", "result", dir.to_str().unwrap()])
+            .status
+            .success()
+    );
+
+    let out = cheat(&["exercise", "check", dir.to_str().unwrap()]);
+    assert!(!out.status.success());
+    let text = String::from_utf8(out.stdout).unwrap();
+    assert!(
+        text.contains("  FAIL  zero_is_none\n        hint: zero is not positive"),
+        "{text}"
+    );
+    assert!(
+        text.contains(
+            "0/3 passed; reference: `cheat show result` (crates/cheat_sheet/src/basics/result.rs:"
+        ),
+        "{text}"
+    );
+
+    let solved = lib.replace("todo!()", "if n > 0 { Some(n) } else { None }");
+    std::fs::write(dir.join("src/lib.rs"), solved).unwrap();
+    let out = stdout(&["exercise", "check", dir.to_str().unwrap()]);
+    assert!(out.contains("  ok    zero_is_none\n"), "{out}");
+    assert!(out.contains("\n3/3 passed;"), "{out}");
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn export_markdown() {
+    let out = stdout(&["export", "--format", "markdown"]);
+    assert!(out.starts_with("# Rust cheat sheet\n"));
+    assert!(out.contains("## 12) Lifetimes (minimal)"));
+    assert_eq!(out, stdout(&["export"]));
+    assert!(!cheat(&["export", "--format", "pdf"]).status.success());
+}
+
+#[test]
+fn export_json() {
+    let out = stdout(&["export", "--format", "json"]);
+    assert!(out.starts_with("{\n  \"version\": 1,"), "{out}");
+    assert!(out.contains("\"id\": \"types/references\""));
+    assert!(out.contains("\"file\": \"crates/cheat_sheet/src/basics/lifetimes.rs\""));
+    assert!(out.contains("\"error\": \"E0502\""));
+}
+
+#[test]
+fn build_site_writes_pages() {
+    let dir = std::env::temp_dir().join(format!("cheat-site-{}", std::process::id()));
+    let out = stdout(&["build-site", dir.to_str().unwrap()]);
+    assert!(out.starts_with("wrote "), "{out}");
+    for page in [
+        "index.html",
+        "tags.html",
+        "basics/vec.html",
+        "assets/site.css",
+    ] {
+        assert!(dir.join(page).is_file(), "{page}");
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
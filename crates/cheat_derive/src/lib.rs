@@ -0,0 +1,242 @@
+//! `#[derive(Speak)]` for the cheat sheet's [`Speak`] trait.
+//!
+//! The derived `speak()` says the prefix, then for enums the variant name in
+//! snake_case, then each field `Debug`-formatted (`name=value` for named
+//! fields). The prefix is the type name in lower case unless the type says
+//! otherwise with `#[speak(prefix = "...")]`:
+//!
+//! ```ignore
+//! #[derive(Debug, Speak)]
+//! #[speak(prefix = "num")]
+//! struct Meters(i32);
+//!
+//! assert_eq!(Meters(7).speak(), "num 7");
+//! ```
+//!
+//! The generated impl names the trait as `::cheat_sheet::shared::Speak`, so it
+//! works in any crate depending on `cheat_sheet` (which refers to itself by
+//! that name too). Mistakes are reported at the offending tokens, see
+//! `tests/ui/`.
+//!
+//! [`Speak`]: https://docs.rs/cheat_sheet/latest/cheat_sheet/shared/trait.Speak.html
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, LitStr};
+
+#[proc_macro_derive(Speak, attributes(speak))]
+pub fn derive_speak(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let prefix = match prefix(&input.attrs)? {
+        Some(prefix) => prefix,
+        None => input.ident.unraw().to_string().to_lowercase(),
+    };
+    let body = match &input.data {
+        Data::Struct(data) => {
+            no_speak_attrs(&data.fields)?;
+            let (pattern, words) = destructure(quote!(Self), &data.fields);
+            quote! {
+                let #pattern = self;
+                let mut out = ::std::string::String::from(#prefix);
+                #words
+                out
+            }
+        }
+        Data::Enum(data) if data.variants.is_empty() => quote!(match *self {}),
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                reject(&variant.attrs, "a variant")?;
+                no_speak_attrs(&variant.fields)?;
+                let ident = &variant.ident;
+                let name = format!(" {}", snake_case(&ident.unraw().to_string()));
+                let (pattern, words) = destructure(quote!(Self::#ident), &variant.fields);
+                arms.push(quote! {
+                    #pattern => {
+                        out.push_str(#name);
+                        #words
+                    }
+                });
+            }
+            quote! {
+                let mut out = ::std::string::String::from(#prefix);
+                match self {
+                    #(#arms)*
+                }
+                out
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`#[derive(Speak)]` does not support unions",
+            ))
+        }
+    };
+
+    // Every field is `Debug`-formatted, so every type parameter must be Debug.
+    let params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect();
+    let generic = !input.generics.params.is_empty();
+    let where_clause = input.generics.make_where_clause();
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::std::fmt::Debug));
+    }
+    // `Speak: Any` only holds for 'static types; a generic impl has to say so
+    // (a concrete type is checked as is).
+    if generic {
+        where_clause.predicates.push(parse_quote!(Self: 'static));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::cheat_sheet::shared::Speak for #name #ty_generics #where_clause {
+            fn speak(&self) -> ::std::string::String {
+                #body
+            }
+        }
+    })
+}
+
+/// The `prefix` of `#[speak(prefix = "...")]`, if the type has one.
+fn prefix(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut prefix = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("speak")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("prefix") {
+                return Err(meta.error("unknown `speak` option, expected `prefix`"));
+            }
+            if prefix.is_some() {
+                return Err(meta.error("duplicate `prefix`"));
+            }
+            let value: LitStr = meta.value()?.parse()?;
+            if value.value().is_empty() {
+                return Err(syn::Error::new(value.span(), "`prefix` can't be empty"));
+            }
+            prefix = Some(value.value());
+            Ok(())
+        })?;
+    }
+    Ok(prefix)
+}
+
+fn no_speak_attrs(fields: &Fields) -> syn::Result<()> {
+    fields.iter().try_for_each(|f| reject(&f.attrs, "a field"))
+}
+
+fn reject(attrs: &[Attribute], on: &str) -> syn::Result<()> {
+    match attrs.iter().find(|a| a.path().is_ident("speak")) {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            format!("`#[speak]` goes on the type, not on {on}"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// A pattern binding every field of `path`, and the statements appending
+/// each field to `out`.
+///
+/// Fields are bound as `__speak_<name>`, so a field called `out` can't shadow
+/// the buffer.
+fn destructure(path: TokenStream2, fields: &Fields) -> (TokenStream2, TokenStream2) {
+    match fields {
+        Fields::Named(named) => {
+            let idents: Vec<_> = named.named.iter().filter_map(|f| f.ident.clone()).collect();
+            let bindings: Vec<_> = idents
+                .iter()
+                .map(|i| format_ident!("__speak_{}", i.unraw()))
+                .collect();
+            let labels = idents.iter().map(|i| format!(" {}={{:?}}", i.unraw()));
+            (
+                quote!(#path { #(#idents: #bindings),* }),
+                quote!(#(out.push_str(&::std::format!(#labels, #bindings));)*),
+            )
+        }
+        Fields::Unnamed(unnamed) => {
+            let idents: Vec<_> = (0..unnamed.unnamed.len())
+                .map(|i| format_ident!("__speak_{}", i))
+                .collect();
+            (
+                quote!(#path(#(#idents),*)),
+                quote!(#(out.push_str(&::std::format!(" {:?}", #idents));)*),
+            )
+        }
+        Fields::Unit => (path, TokenStream2::new()),
+    }
+}
+
+/// `MoveTo` -> `move_to`; `HTTP` -> `http`, `Http2Get` -> `http2_get`.
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c.is_uppercase() && prev_lower {
+            out.push('_');
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expanded(input: DeriveInput) -> String {
+        expand(input).unwrap().to_string()
+    }
+
+    #[test]
+    fn variant_names_become_snake_case() {
+        assert_eq!(snake_case("Quit"), "quit");
+        assert_eq!(snake_case("MoveTo"), "move_to");
+        assert_eq!(snake_case("HTTP"), "http");
+        assert_eq!(snake_case("Http2Get"), "http2_get");
+    }
+
+    #[test]
+    fn type_parameters_must_be_debug() {
+        let out = expanded(parse_quote!(
+            struct Pair<'a, A, B: Copy>(&'a A, B);
+        ));
+        assert!(
+            out.contains(
+                "A : :: std :: fmt :: Debug , B : :: std :: fmt :: Debug , Self : 'static"
+            ),
+            "{out}"
+        );
+        assert!(!out.contains("'a : 'static"), "{out}");
+        let out = expanded(parse_quote!(
+            struct Meters(i32);
+        ));
+        assert!(!out.contains("where"), "{out}");
+    }
+
+    #[test]
+    fn the_prefix_defaults_to_the_type_name() {
+        let out = expanded(parse_quote!(
+            struct HttpServer;
+        ));
+        assert!(out.contains("String :: from (\"httpserver\")"), "{out}");
+        let out = expanded(parse_quote!(
+            #[speak(prefix = "srv")]
+            struct HttpServer;
+        ));
+        assert!(out.contains("String :: from (\"srv\")"), "{out}");
+    }
+}
@@ -0,0 +1,83 @@
+use cheat_derive::Speak;
+use cheat_sheet::shared::Speak;
+
+#[derive(Debug, Speak)]
+enum Shape {
+    Dot,
+    Circle(f64),
+    Rect { w: u32, h: u32 },
+    RoundedRect(u32, u32, f32),
+}
+
+#[derive(Debug, Speak)]
+#[speak(prefix = "tag")]
+struct Tagged<'a, T: Clone> {
+    name: &'static str,
+    value: Option<T>,
+    #[allow(dead_code)]
+    marker: std::marker::PhantomData<&'a ()>,
+}
+
+#[test]
+fn enums_say_the_variant_then_its_fields() {
+    let said: Vec<_> = [
+        Shape::Dot,
+        Shape::Circle(1.5),
+        Shape::Rect { w: 2, h: 3 },
+        Shape::RoundedRect(2, 3, 0.5),
+    ]
+    .iter()
+    .map(Speak::speak)
+    .collect();
+    assert_eq!(
+        said,
+        [
+            "shape dot",
+            "shape circle 1.5",
+            "shape rect w=2 h=3",
+            "shape rounded_rect 2 3 0.5"
+        ]
+    );
+}
+
+#[test]
+fn generic_structs_keep_their_own_bounds() {
+    let t: Tagged<'static, String> = Tagged {
+        name: "a",
+        value: Some("b".into()),
+        marker: std::marker::PhantomData,
+    };
+    assert_eq!(
+        t.speak(),
+        "tag name=\"a\" value=Some(\"b\") marker=PhantomData<&()>"
+    );
+    assert_eq!(
+        t.shout(),
+        "TAG NAME=\"A\" VALUE=SOME(\"B\") MARKER=PHANTOMDATA<&()>"
+    );
+}
+
+#[test]
+fn derived_speakers_are_trait_objects_too() {
+    let speakers: Vec<Box<dyn Speak>> = vec![Box::new(Shape::Dot), Box::new(7)];
+    let said: Vec<_> = speakers.iter().map(|s| s.speak()).collect();
+    assert_eq!(said, ["shape dot", "num 7"]);
+    let any: &dyn std::any::Any = &*speakers[0];
+    assert!(any.is::<Shape>());
+}
+
+#[derive(Debug, Speak)]
+struct Buf {
+    out: i32,
+}
+
+#[derive(Debug, Speak)]
+enum Io {
+    Write { out: &'static str },
+}
+
+#[test]
+fn fields_named_like_the_generated_buffer() {
+    assert_eq!(Buf { out: 5 }.speak(), "buf out=5");
+    assert_eq!(Io::Write { out: "x" }.speak(), "io write out=\"x\"");
+}
@@ -0,0 +1,8 @@
+// The derive's compile errors, checked against `tests/ui/*.stderr`.
+// After changing a message: `TRYBUILD=overwrite cargo test -p cheat_derive --test ui`
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/*.rs");
+}
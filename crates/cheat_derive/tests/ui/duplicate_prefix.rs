@@ -0,0 +1,8 @@
+use cheat_derive::Speak;
+
+#[derive(Debug, Speak)]
+#[speak(prefix = "num")]
+#[speak(prefix = "m")]
+struct Meters(i32);
+
+fn main() {}
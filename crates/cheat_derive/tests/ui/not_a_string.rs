@@ -0,0 +1,7 @@
+use cheat_derive::Speak;
+
+#[derive(Debug, Speak)]
+#[speak(prefix = 7)]
+struct Meters(i32);
+
+fn main() {}
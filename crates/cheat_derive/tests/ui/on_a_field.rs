@@ -0,0 +1,9 @@
+use cheat_derive::Speak;
+
+#[derive(Debug, Speak)]
+struct User {
+    #[speak(prefix = "name")]
+    name: String,
+}
+
+fn main() {}
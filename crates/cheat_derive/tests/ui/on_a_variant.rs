@@ -0,0 +1,9 @@
+use cheat_derive::Speak;
+
+#[derive(Debug, Speak)]
+enum Msg {
+    #[speak(prefix = "bye")]
+    Quit,
+}
+
+fn main() {}
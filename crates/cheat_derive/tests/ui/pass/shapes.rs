@@ -0,0 +1,21 @@
+use cheat_derive::Speak;
+use cheat_sheet::shared::Speak;
+
+#[derive(Debug, Speak)]
+struct Unit;
+
+#[derive(Debug, Speak)]
+#[speak(prefix = "pt")]
+struct Point<T> {
+    x: T,
+    r#type: &'static str,
+}
+
+#[derive(Debug, Speak)]
+enum Never {}
+
+fn main() {
+    assert_eq!(Unit.speak(), "unit");
+    assert_eq!(Point { x: 1u8, r#type: "int" }.speak(), "pt x=1 type=\"int\"");
+    let _ = |n: &Never| n.speak();
+}
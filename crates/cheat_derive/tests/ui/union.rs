@@ -0,0 +1,9 @@
+use cheat_derive::Speak;
+
+#[derive(Speak)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
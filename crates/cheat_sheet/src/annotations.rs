@@ -0,0 +1,153 @@
+//! Inline expected-output annotations: `//=>` comments next to demo lines.
+//!
+//! ```text
+//! println!("add(2,3)={}", add(2, 3)); //=> add(2,3)=5
+//! ```
+//!
+//! A statement that prints several lines (a `println!` in a loop) continues
+//! its annotation on the following comment-only lines:
+//!
+//! ```text
+//! println!("for i={i}"); //=> for i=0
+//!                        //=> for i=1
+//! ```
+//!
+//! [`check`] verifies that the annotated lines appear in a section's output,
+//! exactly and in order. Unannotated output lines are ignored.
+
+use std::fmt;
+
+use crate::registry::Section;
+use crate::snapshot;
+
+pub(crate) const MARKER: &str = "//=>";
+
+/// One expected output line, written next to the code that prints it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Annotation {
+    /// 1-based line within the section source.
+    pub line: usize,
+    /// The exact output line claimed by the annotation.
+    pub expected: &'static str,
+}
+
+/// An annotation that the output did not satisfy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub file: &'static str,
+    pub annotation: Annotation,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected `{}` was not printed (in order)",
+            self.file, self.annotation.line, self.annotation.expected
+        )
+    }
+}
+
+/// The annotations of a section, in source order.
+pub fn annotations(section: &Section) -> impl Iterator<Item = Annotation> {
+    section.source.lines().enumerate().filter_map(|(i, line)| {
+        let (_, expected) = line.split_once(MARKER)?;
+        Some(Annotation {
+            line: i + 1,
+            expected: expected.strip_prefix(' ').unwrap_or(expected).trim_end(),
+        })
+    })
+}
+
+/// Checks `output` (the section's stdout) against its annotations and returns
+/// how many were satisfied.
+///
+/// Each annotation must match a whole output line, after the previous
+/// annotation's match. Hash-ordered sections compare with entry order ignored.
+pub fn check(section: &Section, output: &str) -> Result<usize, Vec<Mismatch>> {
+    let unordered = section.unordered_output();
+    let lines: Vec<String> = output
+        .lines()
+        .map(|l| snapshot::normalize(l, unordered))
+        .collect();
+    let mut cursor = 0;
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for annotation in annotations(section) {
+        let expected = snapshot::normalize(annotation.expected, unordered);
+        match lines[cursor..].iter().position(|l| *l == expected) {
+            Some(i) => {
+                cursor += i + 1;
+                checked += 1;
+            }
+            None => mismatches.push(Mismatch {
+                file: section.file,
+                annotation,
+            }),
+        }
+    }
+    if mismatches.is_empty() {
+        Ok(checked)
+    } else {
+        Err(mismatches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{self, find};
+
+    #[test]
+    fn continuation_lines_belong_to_the_loop() {
+        let expected: Vec<_> = annotations(find("control_flow").unwrap())
+            .map(|a| a.expected)
+            .collect();
+        assert_eq!(
+            expected,
+            [
+                "n is big",
+                "for i=0",
+                "for i=1",
+                "for i=2",
+                "while i=0",
+                "while i=1",
+                "looped 2 times"
+            ]
+        );
+    }
+
+    #[test]
+    fn every_annotation_matches_its_snapshot() {
+        for section in registry::registry() {
+            if let Err(misses) = check(section, section.output) {
+                let misses: Vec<_> = misses.iter().map(ToString::to_string).collect();
+                panic!("{}", misses.join("\n"));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_wrong_and_out_of_order_lines() {
+        let section = find("functions").unwrap();
+        let err = check(section, "add(2,3)=6\n").unwrap_err();
+        assert_eq!(err[0].annotation.expected, "add(2,3)=5");
+        assert!(err[0]
+            .to_string()
+            .starts_with("crates/cheat_sheet/src/basics/functions.rs:7:"));
+
+        let section = find("enums").unwrap();
+        let err = check(section, "move: 3,4\nwrite: hey\nquit\n").unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err[0].annotation.expected, "move: 3,4");
+    }
+
+    #[test]
+    fn hash_ordered_sections_ignore_entry_order() {
+        let section = find("hashmap").unwrap();
+        assert_eq!(
+            check(section, "map={\"b\": 2, \"a\": 1}, a=Some(1)\n"),
+            Ok(1)
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! 14) Worked example: a command interpreter (COMMANDS)
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use crate::shared::Msg;
+
+pub fn run() {
+    // =========================
+    // 14) Worked example: a command interpreter (COMMANDS)
+    // =========================
+    // FromStr makes `str::parse` work for Msg:
+    let m: Msg = "move 3 4".parse().unwrap();
+    println!("{m:?}"); //=> Move { x: 3, y: 4 }
+    let w: Result<Msg, _> = "write hello world".parse();
+    println!("{w:?}"); //=> Ok(Write("hello world"))
+
+    // Bad input is a typed error, matched like any other enum:
+    match "move 3 x".parse::<Msg>() {
+        Err(ParseMsgError::BadNumber { arg, .. }) => println!("bad number: {arg}"), //=> bad number: x
+        other => println!("unexpected: {other:?}"),
+    }
+    println!("{}", "jump".parse::<Msg>().unwrap_err()); //=> unknown command `jump` (expected quit, write or move)
+
+    // A script: one command per line, `#` comments, stops at `quit`.
+    // Anything BufRead works: bytes here, `io::stdin().lock()` for real input.
+    let script = "write hey\nmove 3 4\n# a comment\nmove -1 0\nquit\nwrite never";
+    let mut state = State::default();
+    match state.run_script(script.as_bytes()) {
+        Ok(n) => println!("ran {n} commands: {state:?}"), //=> ran 4 commands: State { x: 2, y: 4, buffer: "hey", running: false }
+        Err(e) => println!("script error: {e}"),
+    }
+    if let Err(e) = State::default().run_script("write a\nmove 1\n".as_bytes()) {
+        println!("{e}"); //=> line 2: `move` takes 2 arguments, got 1
+    }
+}
+
+// -------------------------
+// Parsing: text -> Msg, or a typed error saying what was wrong
+// -------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMsgError {
+    Empty,
+    UnknownCommand(String),
+    MissingText,
+    WrongArgCount { command: &'static str, expected: usize, got: usize },
+    BadNumber { arg: String, source: ParseIntError },
+}
+
+impl fmt::Display for ParseMsgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMsgError::Empty => write!(f, "empty command"),
+            ParseMsgError::UnknownCommand(c) => {
+                write!(f, "unknown command `{c}` (expected quit, write or move)")
+            }
+            ParseMsgError::MissingText => write!(f, "`write` needs some text"),
+            ParseMsgError::WrongArgCount { command, expected, got } => {
+                write!(f, "`{command}` takes {expected} arguments, got {got}")
+            }
+            ParseMsgError::BadNumber { arg, .. } => write!(f, "`{arg}` is not a number"),
+        }
+    }
+}
+
+// `source` exposes the underlying error, so reporters can print the chain.
+impl Error for ParseMsgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseMsgError::BadNumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// `quit` | `write <text>` | `move <x> <y>`
+impl FromStr for Msg {
+    type Err = ParseMsgError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim_start();
+        let args: Vec<&str> = rest.split_whitespace().collect();
+        let count = |command, expected| {
+            if args.len() == expected {
+                Ok(())
+            } else {
+                Err(ParseMsgError::WrongArgCount { command, expected, got: args.len() })
+            }
+        };
+        let number = |arg: &str| {
+            arg.parse::<i32>()
+                .map_err(|source| ParseMsgError::BadNumber { arg: arg.to_string(), source })
+        };
+        match command {
+            "" => Err(ParseMsgError::Empty),
+            "quit" => count("quit", 0).map(|_| Msg::Quit),
+            "write" if rest.is_empty() => Err(ParseMsgError::MissingText),
+            "write" => Ok(Msg::Write(rest.to_string())),
+            "move" => {
+                count("move", 2)?;
+                Ok(Msg::Move { x: number(args[0])?, y: number(args[1])? })
+            }
+            other => Err(ParseMsgError::UnknownCommand(other.to_string())),
+        }
+    }
+}
+
+// -------------------------
+// Interpreting: apply each Msg to a State
+// -------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct State {
+    pub x: i32,
+    pub y: i32,
+    pub buffer: String,
+    pub running: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State { x: 0, y: 0, buffer: String::new(), running: true }
+    }
+}
+
+impl State {
+    pub fn apply(&mut self, msg: Msg) {
+        match msg {
+            Msg::Quit => self.running = false,
+            Msg::Write(text) => self.buffer.push_str(&text),
+            Msg::Move { x, y } => {
+                self.x = self.x.saturating_add(x); // moves are relative
+                self.y = self.y.saturating_add(y);
+            }
+        }
+    }
+
+    // Runs lines until `quit` or the end; returns how many commands ran.
+    pub fn run_script(&mut self, input: impl BufRead) -> Result<usize, ScriptError> {
+        let mut ran = 0;
+        for (i, line) in input.lines().enumerate() {
+            let line = line?; // io::Error -> ScriptError via From
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let msg = line.parse().map_err(|error| ScriptError::Parse { line: i + 1, error })?;
+            self.apply(msg);
+            ran += 1;
+            if !self.running {
+                break;
+            }
+        }
+        Ok(ran)
+    }
+}
+
+// A script fails on a bad line (with its number) or on a read error.
+#[derive(Debug)]
+pub enum ScriptError {
+    Io(io::Error),
+    Parse { line: usize, error: ParseMsgError },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Io(_) => write!(f, "could not read the script"),
+            ScriptError::Parse { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+impl Error for ScriptError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScriptError::Io(e) => Some(e),
+            ScriptError::Parse { error, .. } => error.source(),
+        }
+    }
+}
+
+impl From<io::Error> for ScriptError {
+    fn from(e: io::Error) -> Self {
+        ScriptError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Msg, ParseMsgError> {
+        line.parse()
+    }
+
+    #[test]
+    fn parses_every_command() {
+        assert_eq!(parse("quit"), Ok(Msg::Quit));
+        assert_eq!(parse("  write hi  there "), Ok(Msg::Write("hi  there".into())));
+        assert_eq!(parse("move -3 4"), Ok(Msg::Move { x: -3, y: 4 }));
+        assert_eq!(parse("move\t3\t4"), Ok(Msg::Move { x: 3, y: 4 }));
+        assert_eq!(parse("write\thi"), Ok(Msg::Write("hi".into())));
+    }
+
+    #[test]
+    fn empty_line() {
+        assert_eq!(parse("   "), Err(ParseMsgError::Empty));
+        assert_eq!(ParseMsgError::Empty.to_string(), "empty command");
+    }
+
+    #[test]
+    fn unknown_command() {
+        assert_eq!(parse("jump 1"), Err(ParseMsgError::UnknownCommand("jump".into())));
+        assert_eq!(parse("QUIT"), Err(ParseMsgError::UnknownCommand("QUIT".into())));
+    }
+
+    #[test]
+    fn write_without_text() {
+        assert_eq!(parse("write"), Err(ParseMsgError::MissingText));
+        assert_eq!(parse("write   "), Err(ParseMsgError::MissingText));
+    }
+
+    #[test]
+    fn wrong_argument_count() {
+        let wrong = |command, expected, got| Err(ParseMsgError::WrongArgCount { command, expected, got });
+        assert_eq!(parse("move 3"), wrong("move", 2, 1));
+        assert_eq!(parse("move 1 2 3"), wrong("move", 2, 3));
+        assert_eq!(parse("quit now"), wrong("quit", 0, 1));
+        assert_eq!(parse("move").unwrap_err().to_string(), "`move` takes 2 arguments, got 0");
+    }
+
+    #[test]
+    fn bad_number() {
+        let err = parse("move 3 x").unwrap_err();
+        assert!(matches!(&err, ParseMsgError::BadNumber { arg, .. } if arg == "x"));
+        assert_eq!(err.to_string(), "`x` is not a number");
+        assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+        assert!(matches!(parse("move 1 99999999999"), Err(ParseMsgError::BadNumber { .. })));
+    }
+
+    #[test]
+    fn scripts_stop_at_quit_and_report_lines() {
+        let mut state = State::default();
+        let ran = state.run_script("move 1 1\n\n# skip\nwrite ab\nquit\nmove 9 9\n".as_bytes()).unwrap();
+        assert_eq!(ran, 3);
+        assert_eq!(state, State { x: 1, y: 1, buffer: "ab".into(), running: false });
+
+        let err = State::default().run_script("write a\n\nfly\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, ScriptError::Parse { line: 3, error: ParseMsgError::UnknownCommand(_) }));
+    }
+
+    #[test]
+    fn read_errors_become_script_errors() {
+        struct Broken;
+        impl io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+        let err = State::default().run_script(io::BufReader::new(Broken)).unwrap_err();
+        assert_eq!(err.to_string(), "could not read the script");
+        assert_eq!(err.source().unwrap().to_string(), "disk on fire");
+    }
+}
@@ -0,0 +1,32 @@
+//! 3) Control Flow
+
+pub fn run() {
+    // =========================
+    // 3) Control Flow
+    // =========================
+    let n = 7;
+    let v = if n > 5 { "big" } else { "small" };
+    println!("n is {v}"); //=> n is big
+
+    for i in 0..3 {
+        println!("for i={i}"); //=> for i=0
+                               //=> for i=1
+                               //=> for i=2
+    }
+
+    let mut i = 0;
+    while i < 2 {
+        println!("while i={i}"); //=> while i=0
+                                 //=> while i=1
+        i += 1;
+    }
+
+    let mut loops = 0;
+    loop {
+        loops += 1;
+        if loops == 2 {
+            break;
+        }
+    }
+    println!("looped {loops} times"); //=> looped 2 times
+}
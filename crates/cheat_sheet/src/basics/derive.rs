@@ -0,0 +1,41 @@
+//! 17) Derive macros: #[derive(Speak)] (needs the `derive` feature)
+
+use cheat_derive::Speak;
+
+use crate::basics::User;
+// Same name, different namespaces: the derive macro above, the trait here.
+use crate::shared::{Msg, Speak};
+
+pub fn run() {
+    // =========================
+    // 17) Derive macros: #[derive(Speak)]
+    // =========================
+    // A derive is a proc macro: Rust code that runs at compile time, reading the
+    // type's definition and writing an impl (`macro_rules!` only rearranges tokens).
+    // `cheat_derive` says the type's name, then each field (Debug-formatted).
+    // User and Msg derive it behind `cfg_attr(feature = "derive", ...)`.
+    println!("{}", User::new("alex", 21).speak()); //=> user name="alex" age=21
+    for m in [Msg::Quit, Msg::Write("hi".into()), Msg::Move { x: 3, y: 4 }] {
+        println!("{}", m.speak()); //=> msg quit
+                                   //=> msg write "hi"
+                                   //=> msg move x=3 y=4
+    }
+
+    // `#[speak(prefix = "...")]` replaces the type name
+    println!("{}", Meters(7).speak()); //=> num 7
+    println!("{}", Meters(7).shout()); //=> NUM 7
+
+    // Generic parameters get `T: Debug` bounds on the impl (plus `Self: 'static`,
+    // which Speak's supertrait Any needs)
+    println!("{}", Pair("a", 1.5).speak()); //=> pair "a" 1.5
+
+    // Mistakes are compile errors at the attribute (see cheat_derive/tests/ui):
+    // #[speak(prefx = "num")] // error: unknown `speak` option, expected `prefix`
+}
+
+#[derive(Debug, Speak)]
+#[speak(prefix = "num")]
+pub struct Meters(pub i32);
+
+#[derive(Debug, Speak)]
+pub struct Pair<A, B>(pub A, pub B);
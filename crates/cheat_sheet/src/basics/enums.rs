@@ -0,0 +1,23 @@
+//! 9) Enums + match
+
+use crate::shared::Msg;
+
+pub fn run() {
+    // =========================
+    // 9) Enums + match
+    // =========================
+    handle(Msg::Write("hey".into())); //=> write: hey
+    handle(Msg::Move { x: 3, y: 4 }); //=> move: 3,4
+    handle(Msg::Quit); //=> quit
+}
+
+// -------------------------
+// Enums + match
+// -------------------------
+pub fn handle(m: Msg) {
+    match m {
+        Msg::Quit => println!("quit"),
+        Msg::Write(s) => println!("write: {s}"),
+        Msg::Move { x, y } => println!("move: {x},{y}"),
+    }
+}
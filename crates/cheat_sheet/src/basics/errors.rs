@@ -0,0 +1,173 @@
+//! 15) Custom error types (ERRORS)
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+
+use crate::basics::result::{maybe_pos, parse_i32};
+
+pub fn run() {
+    // =========================
+    // 15) Custom error types (ERRORS)
+    // =========================
+    // One error enum for the app; `?` converts into it through `From`
+    println!("{:?}", positive("42")); //=> Ok(42)
+    for input in ["x1", "-3"] {
+        match positive(input) {
+            Ok(n) => println!("{input}: {n}"),
+            Err(e) => println!("{input}: {e}"), //=> x1: not a number
+                                                //=> -3: invalid input: -3 is not positive
+        }
+    }
+    // Callers can still match on what went wrong:
+    if let Err(AppError::Validation(why)) = positive("0") {
+        println!("rejected: {why}"); //=> rejected: 0 is not positive
+    }
+
+    // I/O, parse and validation errors meet in one function full of `?`
+    println!("{:?}", sum_positive("4\n5\n".as_bytes())); //=> Ok(9)
+
+    // Display says what failed; `source()` says why. A reporter walks the chain:
+    let err = sum_positive("4\nfive\n".as_bytes()).unwrap_err();
+    println!("{}", report(&err)); //=> error: bad input on line 2
+                                  //=>   caused by: not a number
+                                  //=>   caused by: invalid digit found in string
+}
+
+// -------------------------
+// AppError: every failure, with From for each underlying error
+// -------------------------
+#[derive(Debug)]
+pub enum AppError {
+    Parse(ParseIntError),
+    Io(io::Error),
+    Validation(String),
+    // Context around another AppError (boxed: the enum can't contain itself)
+    Line { line: usize, source: Box<AppError> },
+}
+
+// Don't repeat the source's message here: reporters print it from `source()`.
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Parse(_) => write!(f, "not a number"),
+            AppError::Io(_) => write!(f, "could not read input"),
+            AppError::Validation(why) => write!(f, "invalid input: {why}"),
+            AppError::Line { line, .. } => write!(f, "bad input on line {line}"),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Parse(e) => Some(e),
+            AppError::Io(e) => Some(e),
+            AppError::Validation(_) => None,
+            AppError::Line { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+impl From<ParseIntError> for AppError {
+    fn from(e: ParseIntError) -> Self {
+        AppError::Parse(e)
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Io(e)
+    }
+}
+
+// -------------------------
+// Using it: `?` on ParseIntError / io::Error, `ok_or_else` for Options
+// -------------------------
+pub fn positive(s: &str) -> Result<i32, AppError> {
+    let n = parse_i32(s.trim())?; // ParseIntError -> AppError::Parse
+    maybe_pos(n).ok_or_else(|| AppError::Validation(format!("{n} is not positive")))
+}
+
+pub fn sum_positive(input: impl BufRead) -> Result<i32, AppError> {
+    let mut total: i32 = 0;
+    for (i, line) in input.lines().enumerate() {
+        let line = line?; // io::Error -> AppError::Io
+        let n = positive(&line).map_err(|e| AppError::Line { line: i + 1, source: Box::new(e) })?;
+        total = total
+            .checked_add(n)
+            .ok_or_else(|| AppError::Validation("the sum overflows i32".into()))?;
+    }
+    Ok(total)
+}
+
+// Works for any error, not just AppError.
+pub fn report(err: &dyn Error) -> String {
+    let mut out = format!("error: {err}");
+    let mut cause = err.source();
+    while let Some(e) = cause {
+        out.push_str(&format!("\n  caused by: {e}"));
+        cause = e.source();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_int_errors_convert_with_question_mark() {
+        fn parse(s: &str) -> Result<i32, AppError> {
+            Ok(s.parse::<i32>()?)
+        }
+        let err = parse("1.5").unwrap_err();
+        assert!(matches!(&err, AppError::Parse(e) if e.to_string() == "invalid digit found in string"));
+        assert_eq!(err.to_string(), "not a number");
+        assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn io_errors_convert_with_question_mark() {
+        struct Broken;
+        impl io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::PermissionDenied, "no access"))
+            }
+        }
+        let err = sum_positive(io::BufReader::new(Broken)).unwrap_err();
+        assert!(matches!(&err, AppError::Io(e) if e.kind() == io::ErrorKind::PermissionDenied));
+        assert_eq!(report(&err), "error: could not read input\n  caused by: no access");
+    }
+
+    #[test]
+    fn failed_validation_has_no_source() {
+        let err = positive("-7").unwrap_err();
+        assert!(matches!(&err, AppError::Validation(why) if why == "-7 is not positive"));
+        assert!(err.source().is_none());
+        assert_eq!(report(&err), "error: invalid input: -7 is not positive");
+
+        let err = sum_positive("2147483647\n1\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, AppError::Validation(_)));
+    }
+
+    #[test]
+    fn line_context_keeps_the_cause() {
+        let err = sum_positive("1\n\n".as_bytes()).unwrap_err();
+        let AppError::Line { line, source } = &err else { panic!("{err:?}") };
+        assert_eq!(*line, 2);
+        assert!(matches!(**source, AppError::Parse(_)));
+        assert_eq!(
+            report(&err),
+            "error: bad input on line 2\n  caused by: not a number\n  caused by: cannot parse integer from empty string"
+        );
+    }
+
+    #[test]
+    fn report_takes_any_error() {
+        let err = "x".parse::<u8>().unwrap_err();
+        assert_eq!(report(&err), "error: invalid digit found in string");
+        assert_eq!(sum_positive("3\n 4 \n".as_bytes()).unwrap(), 7);
+    }
+}
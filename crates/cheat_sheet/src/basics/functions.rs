@@ -0,0 +1,25 @@
+//! 2) Functions
+
+pub fn run() {
+    // =========================
+    // 2) Functions
+    // =========================
+    println!("add(2,3)={}", add(2, 3)); //=> add(2,3)=5
+}
+
+// -------------------------
+// Functions
+// -------------------------
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b // last expression is return value (no semicolon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(add(2, 3), 5);
+    }
+}
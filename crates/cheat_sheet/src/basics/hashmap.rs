@@ -0,0 +1,14 @@
+//! 7) Collections: HashMap (HASHMAP)
+
+use std::collections::HashMap;
+
+pub fn run() {
+    // =========================
+    // 7) Collections: HashMap (HASHMAP)
+    // =========================
+    let mut m: HashMap<&str, i32> = HashMap::new();
+    m.insert("a", 1);
+    m.entry("b").or_insert(2);
+    let a_val = m.get("a"); // Option<&i32>
+    println!("map={:?}, a={a_val:?}", m); //=> map={"a": 1, "b": 2}, a=Some(1)
+}
@@ -0,0 +1,26 @@
+//! 12) Lifetimes (minimal)
+
+pub fn run() {
+    // =========================
+    // 12) Lifetimes (minimal)
+    // =========================
+    let longer = pick_longer("short", "looooong");
+    println!("longer: {longer}"); //=> longer: looooong
+}
+
+// -------------------------
+// Lifetimes (minimal example)
+// -------------------------
+pub fn pick_longer<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() > b.len() { a } else { b }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_longer() {
+        assert_eq!(pick_longer("aa", "bbbb"), "bbbb");
+    }
+}
@@ -0,0 +1,176 @@
+//! 16) Declarative macros: macro_rules! (MACROS)
+
+pub fn run() {
+    // =========================
+    // 16) Declarative macros: macro_rules! (MACROS)
+    // =========================
+    // A macro matches tokens against its rules and expands to code, before
+    // type checking. The std ones too: `vec![1, 2, 3]` expands to (roughly)
+    //     <[_]>::into_vec(Box::new([1, 2, 3]))
+    // and `println!("{v:?}")` to `std::io::_print(format_args!("{v:?}\n"))`.
+    // `cargo expand` (a cargo plugin) prints any crate fully expanded.
+    let v = vec![1, 2, 3];
+    println!("{v:?}"); //=> [1, 2, 3]
+
+    // hashmap!: a map literal instead of `HashMap::new()` plus one `insert` each
+    let m = hashmap! { "a" => 1, "b" => 2 };
+    // expands to:
+    //     { let mut map = HashMap::new(); map.insert("a", 1); map.insert("b", 2); map }
+    println!("a={}, b={}, len={}", m["a"], m["b"], m.len()); //=> a=1, b=2, len=2
+
+    // max!: `$(...)*` repeats its body once per argument, no recursion
+    println!("{}", max!(3, 9, 4)); //=> 9
+    // expands to:
+    //     { let mut max = 3; let next = 9; if next > max { max = next; }
+    //       let next = 4; if next > max { max = next; } max }
+    println!("{}", max!(2.5)); //=> 2.5
+    // max!(); // error: unexpected end of macro invocation
+
+    // rpn!: a TT muncher eats one token, then recurses on the rest
+    println!("{}", rpn!(3 4 + 2 *)); //=> 14
+    // expands one rule at a time (`@[..]` is the stack, top first):
+    //     rpn!(@[] 3 4 + 2 *)       start with an empty stack
+    //     rpn!(@[3] 4 + 2 *)        operands are pushed
+    //     rpn!(@[4, 3] + 2 *)
+    //     rpn!(@[(3 + 4)] 2 *)      operators pop two and push one
+    //     rpn!(@[2, (3 + 4)] *)
+    //     rpn!(@[((3 + 4) * 2)])
+    //     ((3 + 4) * 2)             one value left: the result
+    let x = 10;
+    println!("{}", rpn!(x 1 - 3 /)); //=> 3
+}
+
+// -------------------------
+// hashmap!: `key => value` pairs, optional trailing comma
+// -------------------------
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)] // `hashmap! {}` inserts nothing
+        let mut map = ::std::collections::HashMap::new();
+        $( map.insert($key, $value); )*
+        map
+    }};
+}
+// Makes the macro a path-based item like any other: usable above its
+// definition, and from other modules with `use`. (`#[macro_export]` would
+// export it from the crate root, for other crates to use.)
+pub(crate) use hashmap;
+
+// -------------------------
+// max!: the largest of one or more values, each evaluated once
+// -------------------------
+macro_rules! max {
+    ($first:expr $(, $rest:expr)* $(,)?) => {{
+        #[allow(unused_mut)] // with one argument, nothing reassigns it
+        let mut max = $first;
+        $(
+            let next = $rest;
+            if next > max {
+                max = next;
+            }
+        )*
+        max
+    }};
+}
+pub(crate) use max;
+
+// -------------------------
+// rpn!: reverse Polish notation, as a TT muncher
+// -------------------------
+// Internal rules start with `@` so they can't clash with user input; the
+// catch-all entry rule comes last because rules are tried in order. The
+// recursive calls resolve `rpn!` where it's invoked, so import it first.
+macro_rules! rpn {
+    (@[$b:expr, $a:expr $(, $stack:expr)*] + $($rest:tt)*) => {
+        rpn!(@[($a + $b) $(, $stack)*] $($rest)*)
+    };
+    (@[$b:expr, $a:expr $(, $stack:expr)*] - $($rest:tt)*) => {
+        rpn!(@[($a - $b) $(, $stack)*] $($rest)*)
+    };
+    (@[$b:expr, $a:expr $(, $stack:expr)*] * $($rest:tt)*) => {
+        rpn!(@[($a * $b) $(, $stack)*] $($rest)*)
+    };
+    (@[$b:expr, $a:expr $(, $stack:expr)*] / $($rest:tt)*) => {
+        rpn!(@[($a / $b) $(, $stack)*] $($rest)*)
+    };
+    (@[$($stack:expr),*] $operand:literal $($rest:tt)*) => {
+        rpn!(@[$operand $(, $stack)*] $($rest)*)
+    };
+    (@[$($stack:expr),*] $operand:ident $($rest:tt)*) => {
+        rpn!(@[$operand $(, $stack)*] $($rest)*)
+    };
+    (@[$result:expr]) => {
+        $result
+    };
+    (@[$($stack:expr),*]) => {
+        ::std::compile_error!(::std::concat!(
+            "rpn! needs exactly one value left, found: ",
+            ::std::stringify!($($stack),*)
+        ))
+    };
+    (@ $($rest:tt)*) => {
+        ::std::compile_error!(::std::concat!(
+            "rpn! expected a number, a variable, or an operator with two values to use, found: ",
+            ::std::stringify!($($rest)*)
+        ))
+    };
+    ($($tokens:tt)+) => {
+        rpn!(@[] $($tokens)+)
+    };
+}
+pub(crate) use rpn;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    #[test]
+    fn hashmap_builds_the_same_map_as_inserts() {
+        let mut expected = HashMap::new();
+        expected.insert("a", 1);
+        expected.insert("b", 2);
+        assert_eq!(hashmap! { "a" => 1, "b" => 2 }, expected);
+        assert_eq!(hashmap! { "a" => 1, "b" => 2, }, expected); // trailing comma
+        // Later duplicates win, as with `insert`.
+        assert_eq!(hashmap! { "a" => 0, "a" => 1, "b" => 2 }, expected);
+        let empty: HashMap<&str, i32> = hashmap! {};
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn max_takes_any_number_of_arguments() {
+        assert_eq!(max!(7), 7);
+        assert_eq!(max!(3, 9, 4), 9);
+        assert_eq!(max!(-1, -5,), -1);
+        assert_eq!(max!("pear", "apple"), "pear");
+        assert_eq!(max!(0.5, f64::NAN, 2.0), 2.0); // NaN never compares greater
+    }
+
+    #[test]
+    fn max_evaluates_each_argument_once() {
+        let calls = Cell::new(0);
+        let tick = |n| {
+            calls.set(calls.get() + 1);
+            n
+        };
+        assert_eq!(max!(tick(1), tick(3), tick(2)), 3);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn rpn_matches_the_infix_expansion() {
+        assert_eq!(rpn!(3 4 + 2 *), (3 + 4) * 2);
+        assert_eq!(rpn!(3 4 2 * +), 3 + 4 * 2);
+        assert_eq!(rpn!(10 4 - 3 -), (10 - 4) - 3); // left to right
+        assert_eq!(rpn!(1.5 2.0 /), 0.75);
+        assert_eq!(rpn!(42), 42);
+    }
+
+    #[test]
+    fn rpn_operands_can_be_variables() {
+        let (w, h) = (3, 4);
+        assert_eq!(rpn!(w h * 2 *), 24);
+        assert_eq!(rpn!(w w * h h * +), 25);
+    }
+}
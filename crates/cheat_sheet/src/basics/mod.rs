@@ -0,0 +1,55 @@
+//! rust_cheat_sheet: a numbered walk-through of everyday Rust.
+//!
+//! Tip: look sections up by tag (OWNERSHIP, STRINGS, VEC, HASHMAP, RESULT,
+//! TRAITS) with [`crate::registry::with_tag`].
+
+pub mod variables;
+pub mod functions;
+pub mod control_flow;
+pub mod ownership;
+pub mod strings;
+pub mod vec;
+pub mod hashmap;
+pub mod structs;
+pub mod enums;
+pub mod result;
+pub mod traits;
+pub mod lifetimes;
+pub mod patterns;
+pub mod commands;
+pub mod errors;
+pub mod macros;
+#[cfg(feature = "derive")]
+pub mod derive;
+
+pub use crate::shared::{id, Msg, Speak};
+pub use commands::{ParseMsgError, ScriptError, State};
+pub use enums::handle;
+pub use errors::{positive, report, sum_positive, AppError};
+pub use functions::add;
+pub use lifetimes::pick_longer;
+pub use ownership::{borrow_mut, borrow_str, takes_ownership};
+pub use result::{maybe_pos, parse_i32, wrapper_using_q};
+pub use structs::User;
+
+/// Runs every section of this sheet, in order.
+pub fn run() {
+    variables::run();
+    functions::run();
+    control_flow::run();
+    ownership::run();
+    strings::run();
+    vec::run();
+    hashmap::run();
+    structs::run();
+    enums::run();
+    result::run();
+    traits::run();
+    lifetimes::run();
+    patterns::run();
+    commands::run();
+    errors::run();
+    macros::run();
+    #[cfg(feature = "derive")]
+    derive::run();
+}
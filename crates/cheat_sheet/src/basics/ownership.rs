@@ -0,0 +1,41 @@
+//! 4) Ownership + Borrowing (OWNERSHIP)
+
+pub fn run() {
+    // =========================
+    // 4) Ownership + Borrowing (OWNERSHIP)
+    // =========================
+    let s = String::from("hello");
+    borrow_str(&s);              // borrow immutably (no move)
+    // takes_ownership(s);       // would move `s`: error[E0382] on the next line
+    println!("still have s: {s}"); //=> still have s: hello
+
+    let mut t = String::from("yo");
+    borrow_mut(&mut t);
+    println!("after borrow_mut: {t}"); //=> after borrow_mut: yo!
+
+    // Copy vs Move
+    let a = 123i32;      // Copy
+    let b = a;           // copied
+    println!("a={a}, b={b}"); //=> a=123, b=123
+
+    let v1 = vec![1, 2]; // Move (Vec not Copy)
+    let v2 = v1;         // moved
+    // println!("{:?}", v1); // error[E0382]: use of moved value
+    println!("v2 moved ok: {:?}", v2); //=> v2 moved ok: [1, 2]
+}
+
+// Borrow immutably
+#[allow(clippy::ptr_arg)] // `&String` on purpose; `&str` is the idiomatic choice
+pub fn borrow_str(s: &String) {
+    println!("borrowed: {s}");
+}
+
+// Borrow mutably
+pub fn borrow_mut(s: &mut String) {
+    s.push('!');
+}
+
+// Takes ownership (moves)
+pub fn takes_ownership(s: String) {
+    println!("owned: {s}");
+}
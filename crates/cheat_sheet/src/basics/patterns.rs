@@ -0,0 +1,19 @@
+//! 13) Pattern tricks
+
+pub fn run() {
+    // =========================
+    // 13) Pattern tricks
+    // =========================
+    let (p, q) = (1, 2);
+    println!("tuple destructure: p={p}, q={q}"); //=> tuple destructure: p=1, q=2
+
+    if let Some(x) = Some(5) {
+        println!("if let got {x}"); //=> if let got 5
+    }
+
+    match 5 {
+        1..=3 => println!("1..=3"),
+        4 | 5 => println!("4 or 5"), //=> 4 or 5
+        _ => println!("other"),
+    }
+}
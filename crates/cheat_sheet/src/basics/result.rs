@@ -0,0 +1,41 @@
+//! 10) Option + Result (RESULT)
+
+pub fn run() {
+    // =========================
+    // 10) Option + Result (RESULT)
+    // =========================
+    let o = maybe_pos(-1);
+    println!("maybe_pos(-1)={o:?}"); //=> maybe_pos(-1)=None
+
+    match parse_i32("123") {
+        Ok(n) => println!("parsed: {n}"), //=> parsed: 123
+        Err(e) => println!("parse error: {e}"),
+    }
+
+    // ? operator demo (propagate errors)
+    // `?` only works in fns returning Result (or Option). That includes main:
+    // `fn main() -> Result<(), Box<dyn Error>>`, see src/bin/cheat_sheet_boxed.rs.
+    // run() returns (), so here’s a tiny wrapper:
+    match wrapper_using_q() {
+        Ok(n) => println!("wrapper_using_q ok: {n}"), //=> wrapper_using_q ok: 78
+        Err(e) => println!("wrapper_using_q err: {e}"),
+    }
+    // One std error type only goes so far: 15) wraps several in an AppError enum
+}
+
+// -------------------------
+// Option / Result
+// -------------------------
+pub fn maybe_pos(n: i32) -> Option<i32> {
+    if n > 0 { Some(n) } else { None }
+}
+
+pub fn parse_i32(s: &str) -> Result<i32, std::num::ParseIntError> {
+    s.parse::<i32>()
+}
+
+// Using `?` to bubble errors up:
+pub fn wrapper_using_q() -> Result<i32, std::num::ParseIntError> {
+    let n: i32 = "77".parse()?;
+    Ok(n + 1)
+}
@@ -0,0 +1,20 @@
+//! 5) Strings (STRINGS)
+
+pub fn run() {
+    // =========================
+    // 5) Strings (STRINGS)
+    // =========================
+    let mut name = String::new();
+    name.push_str("phntm");
+    name.push('z');
+    let greet = format!("hi, {}", name);
+    println!("{greet}"); //=> hi, phntmz
+
+    let s2: &str = "borrowed str slice";
+    println!("{s2}"); //=> borrowed str slice
+
+    // UTF-8 slicing: be careful. This is safe for ASCII:
+    let ascii = String::from("hello");
+    let slice = &ascii[0..2];
+    println!("slice: {slice}"); //=> slice: he
+}
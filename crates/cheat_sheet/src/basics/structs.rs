@@ -0,0 +1,35 @@
+//! 8) Structs + impl
+
+pub fn run() {
+    // =========================
+    // 8) Structs + impl
+    // =========================
+    let mut user = User::new("alex", 20);
+    user.birthday();
+    println!("user: {:?}, greet={}", user, user.greet()); //=> user: User { name: "alex", age: 21 }, greet=hello alex, age 21
+}
+
+// -------------------------
+// Structs + impl
+// -------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "derive", derive(cheat_derive::Speak))]
+pub struct User {
+    pub name: String,
+    pub age: u32,
+}
+
+impl User {
+    pub fn new(name: &str, age: u32) -> Self {
+        Self { name: name.into(), age }
+    }
+
+    pub fn birthday(&mut self) {
+        self.age += 1;
+    }
+
+    pub fn greet(&self) -> String {
+        format!("hello {}, age {}", self.name, self.age)
+    }
+}
@@ -0,0 +1,14 @@
+//! 11) Generics + Traits (TRAITS)
+
+use crate::shared::{id, Speak};
+
+pub fn run() {
+    // =========================
+    // 11) Generics + Traits (TRAITS)
+    // =========================
+    println!("id(9)={}", id(9)); //=> id(9)=9
+    println!("id(\"hi\")={}", id("hi")); //=> id("hi")=hi
+
+    let spk: i32 = 42;
+    println!("Speak: {}", spk.speak()); //=> Speak: num 42
+}
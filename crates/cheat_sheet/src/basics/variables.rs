@@ -0,0 +1,15 @@
+//! 1) Variables + Types
+
+pub fn run() {
+    // =========================
+    // 1) Variables + Types
+    // =========================
+    let x = 5;               // immutable
+    let mut y: i32 = 10;     // mutable
+    y += 1;
+
+    const MAX: i32 = 99;     // constants must have a type
+    let big = 1_000_000u64;  // underscores ok
+
+    println!("x={x}, y={y}, MAX={MAX}, big={big}"); //=> x=5, y=11, MAX=99, big=1000000
+}
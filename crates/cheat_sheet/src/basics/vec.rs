@@ -0,0 +1,27 @@
+//! 6) Collections: Vec (VEC)
+
+#[allow(clippy::get_first)] // `get(0)` mirrors the `nums[0]` line above it
+pub fn run() {
+    // =========================
+    // 6) Collections: Vec (VEC)
+    // =========================
+    let mut nums = vec![1, 2, 3];
+    nums.push(4);
+
+    // indexing panics if out of bounds:
+    let first = nums[0];
+    // safe access:
+    let maybe_first = nums.get(0); // Option<&i32>
+
+    println!("nums={:?}, first={first}, maybe_first={maybe_first:?}", nums); //=> nums=[1, 2, 3, 4], first=1, maybe_first=Some(1)
+
+    for n in &nums {
+        print!("{n} ");
+    }
+    println!();
+
+    for n in &mut nums {
+        *n += 10;
+    }
+    println!("nums after +10: {:?}", nums); //=> nums after +10: [11, 12, 13, 14]
+}
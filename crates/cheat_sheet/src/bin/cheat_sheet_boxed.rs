@@ -0,0 +1,33 @@
+// The `cheat_sheet` binary with the simplest fallible `main`:
+// `main() -> Result<(), Box<dyn Error>>`, so `?` works at the top level.
+// `cargo run -p cheat_sheet --bin cheat_sheet_boxed -- ownership`
+// `cargo run -p cheat_sheet --bin cheat_sheet_boxed -- --script < moves.txt`
+//
+// Any error converts into `Box<dyn Error>` (strings too). If `main` returns
+// `Err(e)`, std prints `Error: {e:?}` (Debug, not Display) to stderr and the
+// process exits with status 1. For nicer messages or other codes, return a
+// `Termination` type instead, as `cheat_sheet` does.
+
+use std::error::Error;
+use std::io;
+
+use cheat_sheet::basics::State;
+use cheat_sheet::registry;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let ids: Vec<String> = std::env::args().skip(1).collect();
+    if ids == ["--script"] {
+        let mut state = State::default();
+        state.run_script(io::stdin().lock())?; // ScriptError -> Box<dyn Error>
+        println!("{state:?}");
+        return Ok(());
+    }
+    if ids.is_empty() {
+        cheat_sheet::run_all();
+    }
+    for id in &ids {
+        let section = registry::find(id).ok_or_else(|| format!("no section `{id}`"))?; // String -> Box<dyn Error>
+        (section.run)();
+    }
+    Ok(())
+}
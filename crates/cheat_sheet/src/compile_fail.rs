@@ -0,0 +1,239 @@
+//! Compile-fail cases: the commented-out lines that claim an error code.
+//!
+//! The sheets explain mistakes with lines like
+//!
+//! ```text
+//! // println!("{:?}", v1); // error[E0382]: use of moved value
+//! ```
+//!
+//! Any commented-out line whose trailing comment names an `E####` code is a
+//! [`Case`]. [`Case::check`] uncomments that line, compiles the section as a
+//! standalone program with the local `rustc` and reports which error codes the
+//! compiler actually emitted.
+
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::registry::{self, Section, Sheet};
+
+/// One commented-out line that is expected to fail with `expected`.
+#[derive(Debug, Clone, Copy)]
+pub struct Case {
+    pub section: &'static Section,
+    /// 1-based line within `section.source`.
+    pub line: usize,
+    /// The commented-out code, e.g. `println!("{:?}", v1);`.
+    pub code: &'static str,
+    /// The claimed error code, e.g. `E0382`.
+    pub expected: &'static str,
+    /// The trailing comment, e.g. `error[E0382]: use of moved value`.
+    pub note: &'static str,
+}
+
+impl Case {
+    /// The section as a standalone program, with this case's line uncommented.
+    pub fn program(&self) -> String {
+        let mut out = String::new();
+        for (i, line) in self.section.source.lines().enumerate() {
+            if i + 1 == self.line {
+                let indent = &line[..line.len() - line.trim_start().len()];
+                out.push_str(indent);
+                out.push_str(self.code);
+            } else {
+                out.push_str(line);
+            }
+            out.push('\n');
+        }
+        with_main(out)
+    }
+
+    /// Compiles [`program`](Self::program) and returns what rustc said.
+    pub fn check(&self) -> io::Result<Compiled> {
+        compile(&self.program())
+    }
+}
+
+/// Result of a type-check-only `rustc` run.
+#[derive(Debug, Clone)]
+pub struct Compiled {
+    /// `true` when rustc exited successfully.
+    pub ok: bool,
+    /// Error codes in the order rustc reported them, e.g. `["E0382"]`.
+    pub codes: Vec<String>,
+    /// rustc's diagnostics, in `--error-format=short`.
+    pub stderr: String,
+}
+
+impl Compiled {
+    /// `true` if compilation failed and reported `code`.
+    pub fn failed_with(&self, code: &str) -> bool {
+        !self.ok && self.codes.iter().any(|c| c == code)
+    }
+}
+
+const MAIN: &str = "\nfn main() {\n    run();\n}\n";
+
+/// Makes a section's source a program: the sections it imports from (by full
+/// path, like `crate::basics::result::maybe_pos`) inlined as nested modules,
+/// the [shared](crate::shared) module inlined as `mod shared` when anything
+/// imports from it, and a `main`.
+fn with_main(mut source: String) -> String {
+    for sheet in [Sheet::Basics, Sheet::Types] {
+        let used: Vec<&Section> = registry::sheet(sheet)
+            .filter(|s| source.contains(&format!("crate::{sheet}::{}::", s.module())))
+            .collect();
+        if used.is_empty() {
+            continue;
+        }
+        source.push_str(&format!("\nmod {sheet} {{\n"));
+        for s in used {
+            source.push_str(&format!("pub mod {} {{\n{}}}\n", s.module(), s.source));
+        }
+        source.push_str("}\n");
+    }
+    if source.contains("crate::shared::") {
+        source.push_str("\nmod shared {\n");
+        source.push_str(registry::SHARED_SOURCE);
+        source.push_str("}\n");
+    }
+    source.push_str(MAIN);
+    source
+}
+
+/// Every compile-fail case across both sheets, in registry order. Sections
+/// that [need cargo](Section::needs_cargo) are skipped: bare `rustc` can't
+/// build them.
+pub fn cases() -> Vec<Case> {
+    registry::registry()
+        .iter()
+        .filter(|s| !s.needs_cargo())
+        .flat_map(cases_in)
+        .collect()
+}
+
+/// The compile-fail cases of one section.
+pub fn cases_in(section: &'static Section) -> impl Iterator<Item = Case> {
+    section
+        .source
+        .lines()
+        .enumerate()
+        .filter_map(move |(i, line)| {
+            let (code, note) = line.trim_start().strip_prefix("// ")?.split_once("//")?;
+            let code = code.trim();
+            let note = note.trim();
+            Some(Case {
+                section,
+                line: i + 1,
+                code,
+                expected: error_code(note)?,
+                note,
+            })
+        })
+}
+
+/// A section as a standalone program that should compile cleanly.
+pub fn standalone(section: &Section) -> String {
+    with_main(section.source.to_string())
+}
+
+/// Type-checks `source` as a binary crate with the local `rustc`.
+///
+/// Uses `$RUSTC` if set, like cargo does.
+pub fn compile(source: &str) -> io::Result<Compiled> {
+    let dir = scratch_dir()?;
+    let file = dir.join("main.rs");
+    std::fs::write(&file, source)?;
+
+    let output = Command::new(rustc())
+        .args(["--edition=2021", "--crate-type=bin", "--emit=metadata"])
+        .args(["--error-format=short", "-A", "warnings", "--out-dir"])
+        .arg(&dir)
+        .arg(&file)
+        .output();
+    let _ = std::fs::remove_dir_all(&dir);
+    let output = output?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    let codes = stderr
+        .lines()
+        .filter_map(|l| {
+            l.split_once("error[")
+                .and_then(|(_, rest)| error_code(rest))
+        })
+        .map(str::to_owned)
+        .collect();
+    Ok(Compiled {
+        ok: output.status.success(),
+        codes,
+        stderr,
+    })
+}
+
+/// A fresh, empty temporary directory; the caller removes it.
+pub(crate) fn scratch_dir() -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "cheat_sheet-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// `$RUSTC` if set, like cargo does, else `rustc` from `PATH`.
+pub(crate) fn rustc() -> PathBuf {
+    std::env::var_os("RUSTC").map_or_else(|| PathBuf::from("rustc"), PathBuf::from)
+}
+
+/// The first `E` followed by four digits in `text`.
+pub(crate) fn error_code(text: &str) -> Option<&str> {
+    text.char_indices().find_map(|(i, c)| {
+        let code = text.get(i..i + 5)?;
+        (c == 'E' && code[1..].bytes().all(|b| b.is_ascii_digit())).then_some(code)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_commented_out_errors() {
+        let found: Vec<_> = cases()
+            .iter()
+            .map(|c| (c.section.id, c.code, c.expected))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("ownership", "takes_ownership(s);", "E0382"),
+                ("ownership", "println!(\"{:?}\", v1);", "E0382"),
+                ("types/references", "println!(\"{r1}\");", "E0502"),
+                ("types/geometry", "Point { x: 3, y: 4 }.length();", "E0599"),
+                ("types/geometry", "-Point { x: 1u32, y: 2 };", "E0600"),
+            ]
+        );
+    }
+
+    #[test]
+    fn program_uncomments_only_that_line() {
+        let case = cases()[1];
+        let program = case.program();
+        assert!(program.contains("\n    println!(\"{:?}\", v1);\n"));
+        assert!(program.contains("// takes_ownership(s);"));
+        assert!(program.ends_with(MAIN));
+    }
+
+    #[test]
+    fn error_codes() {
+        assert_eq!(
+            error_code("error[E0382]: use of moved value"),
+            Some("E0382")
+        );
+        assert_eq!(error_code("would move `s` (E0505)"), Some("E0505"));
+        assert_eq!(error_code("Every E is not a code: E12"), None);
+    }
+}
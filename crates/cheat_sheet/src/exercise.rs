@@ -0,0 +1,496 @@
+//! Fill-in-the-blank exercises derived from the sheets' helpers.
+//!
+//! An [`Exercise`] names a section and the helper items to copy out of it;
+//! the ones to implement get `todo!()` bodies. [`scaffold`] writes that stub
+//! as a small crate, and [`check`] compiles the learner's `src/lib.rs`
+//! together with hidden tests (with `rustc --test`, no cargo needed) and
+//! reports each case, with hints pointing back at the reference section.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::compile_fail::{rustc, scratch_dir};
+use crate::outline::{self, Item};
+use crate::registry::{self, Section};
+use crate::runner::{self, Limits};
+
+/// A helper to reimplement, with the tests that grade it.
+#[derive(Debug, Clone, Copy)]
+pub struct Exercise {
+    /// ID of the reference section.
+    pub section: &'static str,
+    pub task: &'static str,
+    /// Labels of the section's items copied into the stub, in order.
+    pub items: &'static [&'static str],
+    /// Labels of the items whose function bodies become `todo!()`.
+    pub todo: &'static [&'static str],
+    /// Drop the `'a` annotations from the stub: getting them right is the
+    /// exercise.
+    pub strip_lifetimes: bool,
+    /// The hidden tests.
+    pub cases: &'static [TestCase],
+}
+
+/// One hidden test.
+#[derive(Debug, Clone, Copy)]
+pub struct TestCase {
+    pub name: &'static str,
+    /// The test function's body; the exercise's items are in scope.
+    pub body: &'static str,
+    pub hint: &'static str,
+}
+
+#[rustfmt::skip]
+static EXERCISES: &[Exercise] = &[
+    Exercise {
+        section: "lifetimes",
+        task: "implement `pick_longer` with correct lifetimes",
+        items: &["fn pick_longer"],
+        todo: &["fn pick_longer"],
+        strip_lifetimes: true,
+        cases: &[
+            TestCase {
+                name: "returns_the_second_when_longer",
+                body: "assert_eq!(pick_longer(\"short\", \"looooong\"), \"looooong\");",
+                hint: "compare `a.len()` and `b.len()` and return the longer one",
+            },
+            TestCase {
+                name: "returns_the_first_when_longer",
+                body: "assert_eq!(pick_longer(\"looooong\", \"short\"), \"looooong\");",
+                hint: "both branches must return a reference, `a` or `b`",
+            },
+            TestCase {
+                name: "works_with_owned_strings",
+                body: "let a = String::from(\"outer\");\n\
+                       let longer;\n\
+                       {\n    let b = String::from(\"in\");\n    longer = pick_longer(&a, &b).to_string();\n}\n\
+                       assert_eq!(longer, \"outer\");",
+                hint: "the result borrows from both inputs: tie all three to one lifetime `'a`",
+            },
+        ],
+    },
+    Exercise {
+        section: "result",
+        task: "make `maybe_pos` return None for zero and negatives",
+        items: &["fn maybe_pos"],
+        todo: &["fn maybe_pos"],
+        strip_lifetimes: false,
+        cases: &[
+            TestCase {
+                name: "positive_is_some",
+                body: "assert_eq!(maybe_pos(5), Some(5));",
+                hint: "wrap the number in `Some` when it is positive",
+            },
+            TestCase {
+                name: "zero_is_none",
+                body: "assert_eq!(maybe_pos(0), None);",
+                hint: "zero is not positive: the test is `n > 0`, not `n >= 0`",
+            },
+            TestCase {
+                name: "negative_is_none",
+                body: "assert_eq!(maybe_pos(-1), None);",
+                hint: "return `None` when there is no value to give back",
+            },
+        ],
+    },
+    Exercise {
+        section: "types/trait_object",
+        task: "implement `Speak for String`",
+        items: &[
+            "trait Speak",
+            "impl Speak for i32",
+            "impl Speak for String",
+        ],
+        todo: &["impl Speak for String"],
+        strip_lifetimes: false,
+        cases: &[
+            TestCase {
+                name: "string_speaks",
+                body: "assert_eq!(String::from(\"yo\").speak(), \"str yo\");",
+                hint: "mirror `impl Speak for i32`, with a `str ` prefix",
+            },
+            TestCase {
+                name: "works_as_a_trait_object",
+                body: "let things: Vec<Box<dyn Speak>> = vec![Box::new(7i32), Box::new(String::from(\"hi\"))];\n\
+                       let said: Vec<String> = things.iter().map(|t| t.speak()).collect();\n\
+                       assert_eq!(said, [\"num 7\", \"str hi\"]);",
+                hint: "`Box<dyn Speak>` calls whichever impl the boxed value has",
+            },
+        ],
+    },
+    Exercise {
+        section: "types/struct_enum",
+        task: "write `describe(Msg)`",
+        items: &["enum Msg", "fn describe"],
+        todo: &["fn describe"],
+        strip_lifetimes: false,
+        cases: &[
+            TestCase {
+                name: "quit_variant",
+                body: "assert_eq!(describe(Msg::Quit), \"quit\");",
+                hint: "`match` on the message with one arm per variant",
+            },
+            TestCase {
+                name: "write_variant",
+                body: "assert_eq!(describe(Msg::Write(\"hey\".into())), \"write\");",
+                hint: "tuple variants need a pattern for their field: `Msg::Write(_)`",
+            },
+            TestCase {
+                name: "move_variant",
+                body: "assert_eq!(describe(Msg::Move { x: 3, y: 4 }), \"move\");",
+                hint: "struct variants can ignore their fields with `Msg::Move { .. }`",
+            },
+        ],
+    },
+];
+
+/// Every exercise, in sheet order.
+pub fn exercises() -> &'static [Exercise] {
+    EXERCISES
+}
+
+/// The exercise for the section `query` resolves to (an ID or a tag).
+pub fn find(query: &str) -> Option<&'static Exercise> {
+    let section = registry::resolve(query)?;
+    EXERCISES.iter().find(|e| e.section == section.id)
+}
+
+impl Exercise {
+    pub fn reference(&self) -> &'static Section {
+        registry::find(self.section).expect("exercise for a registered section")
+    }
+
+    /// The exercise's items, from its section or the shared module.
+    fn reference_items(&self) -> Vec<Item> {
+        let mut items = outline::outline(self.reference()).items;
+        items.extend(outline::shared_items());
+        self.items
+            .iter()
+            .map(|label| {
+                items
+                    .iter()
+                    .find(|i| i.label() == *label)
+                    .unwrap_or_else(|| panic!("{}: no `{label}`", self.section))
+                    .clone()
+            })
+            .collect()
+    }
+
+    /// The `src/lib.rs` handed out: the items, with `todo!()` bodies to fill.
+    pub fn stub(&self) -> String {
+        let mut out = format!(
+            "//! Exercise: {}.\n//!\n//! Reference: `cheat show {}`. Run `cheat exercise check` when done.\n",
+            self.task, self.section
+        );
+        for item in self.reference_items() {
+            out.push('\n');
+            let source = without_feature_attrs(item.source);
+            let mut source = if self.todo.contains(&item.label().as_str()) {
+                todo_bodies(&source)
+            } else {
+                source
+            };
+            if self.strip_lifetimes {
+                source = source.replace("<'a>", "").replace("&'a ", "&");
+            }
+            out.push_str(&source);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The reference implementation, as a stub would look once solved.
+    pub fn solution(&self) -> String {
+        self.reference_items()
+            .iter()
+            .map(|i| format!("{}\n", i.source))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Where the first item to implement is defined, for hints: `file:line`.
+    pub fn reference_location(&self) -> String {
+        let items = self.reference_items();
+        let todo = items
+            .iter()
+            .find(|i| self.todo.contains(&i.label().as_str()));
+        let (file, _) = todo
+            .and_then(|i| registry::home(i.section))
+            .unwrap_or((self.reference().file, ""));
+        format!("{file}:{}", todo.map_or(1, |i| i.span.line))
+    }
+
+    /// `lib` followed by the hidden tests, as one test crate.
+    fn test_crate(&self, lib: &str) -> String {
+        let mut out = format!("{lib}\n#[cfg(test)]\nmod hidden {{\n    use super::*;\n");
+        for case in self.cases {
+            let body: String = case
+                .body
+                .lines()
+                .map(|l| format!("        {l}\n"))
+                .collect();
+            let _ = write!(
+                out,
+                "\n    #[test]\n    fn {}() {{\n{body}    }}\n",
+                case.name
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Drops `#[cfg_attr(feature = "...", ...)]` lines: the exercise crate has
+/// none of our features, and cargo warns about unknown ones.
+fn without_feature_attrs(source: &str) -> String {
+    source
+        .lines()
+        .filter(|l| !l.trim_start().starts_with("#[cfg_attr(feature ="))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Replaces the body of every `fn` in `source` with `todo!()`.
+fn todo_bodies(source: &str) -> String {
+    let mut out = String::new();
+    let mut skip_to: Option<String> = None;
+    for line in source.lines() {
+        if let Some(end) = &skip_to {
+            if line == end {
+                out.push_str(line);
+                out.push('\n');
+                skip_to = None;
+            }
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+        let trimmed = line.trim_start();
+        let is_fn = trimmed.starts_with("fn ") || trimmed.starts_with("pub fn ");
+        if is_fn && line.ends_with('{') {
+            let indent = &line[..line.len() - trimmed.len()];
+            let _ = writeln!(out, "{indent}    todo!()");
+            skip_to = Some(format!("{indent}}}"));
+        }
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+/// The file in a scaffolded crate that records which exercise it is.
+pub const MARKER_FILE: &str = ".cheat-exercise";
+
+/// Writes the exercise crate into `dir` (`Cargo.toml`, `src/lib.rs` and the
+/// marker). Refuses to overwrite an existing `src/lib.rs`.
+pub fn scaffold(exercise: &Exercise, dir: &Path) -> io::Result<()> {
+    let lib = dir.join("src").join("lib.rs");
+    if lib.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", lib.display()),
+        ));
+    }
+    std::fs::create_dir_all(dir.join("src"))?;
+    let name = exercise.reference().module().replace('_', "-");
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"exercise-{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+             # Not part of any enclosing workspace.\n[workspace]\n"
+        ),
+    )?;
+    std::fs::write(&lib, exercise.stub())?;
+    std::fs::write(dir.join(MARKER_FILE), format!("{}\n", exercise.section))
+}
+
+/// The exercise a scaffolded crate in `dir` is for.
+pub fn scaffolded(dir: &Path) -> io::Result<&'static Exercise> {
+    let marker = std::fs::read_to_string(dir.join(MARKER_FILE)).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!(
+                "{} is not an exercise crate (no {MARKER_FILE})",
+                dir.display()
+            ),
+        )
+    })?;
+    let id = marker.trim();
+    EXERCISES.iter().find(|e| e.section == id).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unknown exercise `{id}`"),
+        )
+    })
+}
+
+/// How one hidden test went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed,
+    /// The crate did not compile, so nothing ran.
+    NotRun,
+}
+
+/// The result of [`check`].
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub exercise: &'static Exercise,
+    pub cases: Vec<(&'static TestCase, Outcome)>,
+    /// rustc's errors when the crate did not compile.
+    pub compile_errors: Option<String>,
+}
+
+impl Report {
+    pub fn passed(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|(_, o)| *o == Outcome::Passed)
+            .count()
+    }
+
+    pub fn all_passed(&self) -> bool {
+        self.passed() == self.cases.len()
+    }
+}
+
+/// How long the hidden tests may run before they count as failed.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Compiles `lib` (the learner's `src/lib.rs`) with the hidden tests and
+/// runs them.
+pub fn check(exercise: &'static Exercise, lib: &str) -> io::Result<Report> {
+    let dir = scratch_dir()?;
+    let result = run_hidden_tests(exercise, lib, &dir);
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+fn run_hidden_tests(exercise: &'static Exercise, lib: &str, dir: &Path) -> io::Result<Report> {
+    let source = dir.join("lib.rs");
+    let binary = dir.join("hidden-tests");
+    std::fs::write(&source, exercise.test_crate(lib))?;
+    let compiled = Command::new(rustc())
+        .args(["--edition=2021", "--test", "--crate-name=exercise"])
+        .args(["--error-format=short", "-A", "warnings", "-o"])
+        .arg(&binary)
+        .arg(&source)
+        .output()?;
+    if !compiled.status.success() {
+        return Ok(Report {
+            exercise,
+            cases: exercise
+                .cases
+                .iter()
+                .map(|c| (c, Outcome::NotRun))
+                .collect(),
+            // Point at the learner's file, not our scratch copy.
+            compile_errors: Some(
+                String::from_utf8_lossy(&compiled.stderr)
+                    .replace(&source.display().to_string(), "src/lib.rs"),
+            ),
+        });
+    }
+
+    let limits = Limits {
+        timeout: TIMEOUT,
+        ..Limits::default()
+    };
+    let output = runner::run(
+        Command::new(&binary)
+            .arg("--test-threads=1")
+            .env("RUST_BACKTRACE", "0"),
+        limits,
+    )?;
+    let stdout = output.stdout;
+
+    // `test hidden::zero_is_none ... ok`; a case with no line (it timed out
+    // or a case before it did) failed.
+    let outcome = |name: &str| {
+        let prefix = format!("test hidden::{name} ... ");
+        match stdout.lines().find_map(|l| l.strip_prefix(&prefix)) {
+            Some("ok") => Outcome::Passed,
+            _ => Outcome::Failed,
+        }
+    };
+    Ok(Report {
+        exercise,
+        cases: exercise
+            .cases
+            .iter()
+            .map(|c| (c, outcome(c.name)))
+            .collect(),
+        compile_errors: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stubs_have_todo_bodies() {
+        let stub = find("result").unwrap().stub();
+        assert!(stub.starts_with("//! Exercise: make `maybe_pos` return None"));
+        assert!(stub.contains("pub fn maybe_pos(n: i32) -> Option<i32> {\n    todo!()\n}\n"));
+        assert!(!stub.contains("n > 0"));
+
+        let stub = find("types/trait_object").unwrap().stub();
+        assert!(stub.contains("impl Speak for i32 {\n    fn speak(&self) -> String {\n        format!(\"num {self}\")\n"));
+        assert!(stub.contains(
+            "impl Speak for String {\n    fn speak(&self) -> String {\n        todo!()\n    }\n}\n"
+        ));
+    }
+
+    #[test]
+    fn stubs_drop_feature_gated_attributes() {
+        let stub = find("types/struct_enum").unwrap().stub();
+        assert!(stub.contains("#[derive(Debug, Clone, PartialEq, Eq)]\npub enum Msg {"));
+        assert!(!stub.contains("cfg_attr"), "{stub}");
+        assert!(!stub.contains("feature ="), "{stub}");
+    }
+
+    #[test]
+    fn lifetimes_are_left_to_the_learner() {
+        let stub = find("LIFETIMES").unwrap().stub();
+        assert!(stub.contains("pub fn pick_longer(a: &str, b: &str) -> &str {\n    todo!()\n}"));
+        assert!(find("lifetimes").unwrap().solution().contains("<'a>"));
+    }
+
+    #[test]
+    fn every_exercise_has_its_items() {
+        for e in exercises() {
+            let solution = e.solution();
+            for label in e.items {
+                let name = label.rsplit(' ').next().unwrap();
+                assert!(solution.contains(name), "{}: {label}", e.section);
+            }
+            assert!(e.todo.iter().all(|t| e.items.contains(t)), "{}", e.section);
+            let location = e.reference_location();
+            assert!(
+                location.starts_with(e.reference().file)
+                    || location.starts_with(registry::SHARED_FILE),
+                "{location}"
+            );
+        }
+        let speak = find("types/trait_object").unwrap();
+        assert!(speak
+            .reference_location()
+            .starts_with("crates/cheat_sheet/src/shared.rs:"));
+        assert!(find("patterns").is_none());
+    }
+
+    #[test]
+    fn hidden_tests_go_in_their_own_module() {
+        let e = find("types/struct_enum").unwrap();
+        let krate = e.test_crate("pub fn describe() {}");
+        assert!(krate
+            .starts_with("pub fn describe() {}\n#[cfg(test)]\nmod hidden {\n    use super::*;\n"));
+        assert!(krate.contains(
+            "    #[test]\n    fn quit_variant() {\n        assert_eq!(describe(Msg::Quit), \"quit\");\n    }\n"
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+//! Compiler error codes explained with the sheet's own demos.
+//!
+//! Each [`Explanation`] points at the lines of the sheet that show the rule
+//! behind an error code (for E0382, the `let v2 = v1;` move), and carries a
+//! one-line fix plus a minimal failing and passing program. The programs are
+//! checked against the local `rustc` by `tests/compile_fail.rs`.
+
+use crate::registry::{self, Section};
+
+/// What the sheet has to say about one `E####` error code.
+#[derive(Debug, Clone, Copy)]
+pub struct Explanation {
+    /// e.g. `E0382`.
+    pub code: &'static str,
+    /// rustc's wording, e.g. `use of moved value`.
+    pub title: &'static str,
+    /// `(section id, line fragment)` of each demo that shows the rule.
+    pub refs: &'static [(&'static str, &'static str)],
+    pub fix: &'static str,
+    /// A whole program that fails with `code`.
+    pub failing: &'static str,
+    /// `failing` with the fix applied; compiles cleanly.
+    pub passing: &'static str,
+}
+
+/// A line of the sheet an explanation points at.
+#[derive(Debug, Clone, Copy)]
+pub struct Demo {
+    pub section: &'static Section,
+    /// 1-based line within `section.source`.
+    pub line: usize,
+    pub text: &'static str,
+}
+
+impl Explanation {
+    /// Resolves [`refs`](Self::refs) to sheet lines, skipping any that no
+    /// longer match (the unit tests keep them all matching).
+    pub fn demos(&self) -> Vec<Demo> {
+        self.refs
+            .iter()
+            .filter_map(|(id, fragment)| {
+                let section = registry::find(id)?;
+                let (line, text) = section.matching_lines(fragment).next()?;
+                Some(Demo {
+                    section,
+                    line,
+                    text,
+                })
+            })
+            .collect()
+    }
+}
+
+#[rustfmt::skip]
+static EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: "E0382",
+        title: "use of moved value",
+        refs: &[("ownership", "let v2 = v1;"), ("ownership", "takes_ownership(s);")],
+        fix: "Borrow instead of moving (`&v1`), or `.clone()` when both owners really need the data.",
+        failing: "\
+fn main() {
+    let v1 = vec![1, 2];
+    let v2 = v1;
+    println!(\"{:?} {:?}\", v1, v2);
+}
+",
+        passing: "\
+fn main() {
+    let v1 = vec![1, 2];
+    let v2 = v1.clone();
+    println!(\"{:?} {:?}\", v1, v2);
+}
+",
+    },
+    Explanation {
+        code: "E0499",
+        title: "cannot borrow as mutable more than once at a time",
+        refs: &[("types/references", "let r2: &mut i32 = &mut n;"), ("ownership", "borrow_mut(&mut t);")],
+        fix: "Finish with the first `&mut` before taking the second; only one can be live at a time.",
+        failing: "\
+fn main() {
+    let mut s = String::from(\"yo\");
+    let a = &mut s;
+    let b = &mut s;
+    a.push('!');
+    b.push('?');
+}
+",
+        passing: "\
+fn main() {
+    let mut s = String::from(\"yo\");
+    let a = &mut s;
+    a.push('!');
+    let b = &mut s;
+    b.push('?');
+}
+",
+    },
+    Explanation {
+        code: "E0502",
+        title: "cannot borrow as mutable because it is also borrowed as immutable",
+        refs: &[("types/references", "println!(\"{r1}\");")],
+        fix: "Use the shared borrow before taking the `&mut` one; a borrow ends at its last use.",
+        failing: "\
+fn main() {
+    let mut n = 5;
+    let r1 = &n;
+    let r2 = &mut n;
+    *r2 += 1;
+    println!(\"{r1}\");
+}
+",
+        passing: "\
+fn main() {
+    let mut n = 5;
+    let r1 = &n;
+    println!(\"{r1}\");
+    let r2 = &mut n;
+    *r2 += 1;
+}
+",
+    },
+    Explanation {
+        code: "E0106",
+        title: "missing lifetime specifier",
+        refs: &[("lifetimes", "pub fn pick_longer<'a>")],
+        fix: "Name a lifetime (`<'a>`) tying the returned reference to the inputs it borrows from.",
+        failing: "\
+fn pick_longer(a: &str, b: &str) -> &str {
+    if a.len() > b.len() { a } else { b }
+}
+
+fn main() {
+    println!(\"{}\", pick_longer(\"short\", \"looooong\"));
+}
+",
+        passing: "\
+fn pick_longer<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() > b.len() { a } else { b }
+}
+
+fn main() {
+    println!(\"{}\", pick_longer(\"short\", \"looooong\"));
+}
+",
+    },
+    Explanation {
+        code: "E0308",
+        title: "mismatched types",
+        refs: &[("types/conversions", "let s2: String = num.to_string();"), ("variables", "let mut y: i32 = 10;")],
+        fix: "Convert explicitly (`.to_string()`, `.into()`, `.parse()`); Rust never converts implicitly.",
+        failing: "\
+fn main() {
+    let num: i32 = 123;
+    let s: String = num;
+    println!(\"{s}\");
+}
+",
+        passing: "\
+fn main() {
+    let num: i32 = 123;
+    let s: String = num.to_string();
+    println!(\"{s}\");
+}
+",
+    },
+];
+
+/// Every explained error code, in the order above.
+pub fn explanations() -> &'static [Explanation] {
+    EXPLANATIONS
+}
+
+/// Looks up `code`, ignoring case; the `E` is optional (`0382` works).
+pub fn find(code: &str) -> Option<&'static Explanation> {
+    let digits = code.strip_prefix(['E', 'e']).unwrap_or(code);
+    EXPLANATIONS.iter().find(|e| &e.code[1..] == digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_ref_points_at_a_sheet_line() {
+        for e in explanations() {
+            assert_eq!(e.demos().len(), e.refs.len(), "{}: stale ref", e.code);
+        }
+    }
+
+    #[test]
+    fn lookup_ignores_case_and_prefix() {
+        assert_eq!(find("E0382").unwrap().code, "E0382");
+        assert_eq!(find("e0106").unwrap().code, "E0106");
+        assert_eq!(find("0499").unwrap().code, "E0499");
+        assert!(find("E9999").is_none());
+        assert!(find("").is_none());
+    }
+
+    #[test]
+    fn demos_are_the_motivating_lines() {
+        let demo = find("E0382").unwrap().demos()[0];
+        assert_eq!(demo.section.id, "ownership");
+        assert_eq!(demo.text.trim(), "let v2 = v1;         // moved");
+        let demo = find("E0106").unwrap().demos()[0];
+        assert_eq!(demo.section.id, "lifetimes");
+        assert_eq!(demo.line, 14);
+    }
+}
@@ -0,0 +1,41 @@
+// Client-side search for `cheat build-site`. Reads `window.CHEAT_INDEX`,
+// written to search-index.js next to this file; no network access.
+(function () {
+  var input = document.getElementById("search");
+  var results = document.getElementById("results");
+  if (!input || !results || !window.CHEAT_INDEX) {
+    return;
+  }
+  var root = document.body.getAttribute("data-root") || "";
+
+  function matches(entry, terms) {
+    var haystack = (entry.title + " " + entry.id + " " + entry.tags.join(" ") + " " + entry.text)
+      .toLowerCase();
+    return terms.every(function (term) {
+      return haystack.indexOf(term) !== -1;
+    });
+  }
+
+  input.addEventListener("input", function () {
+    var terms = input.value.toLowerCase().split(/\s+/).filter(Boolean);
+    results.innerHTML = "";
+    if (terms.length === 0) {
+      return;
+    }
+    window.CHEAT_INDEX.filter(function (entry) {
+      return matches(entry, terms);
+    }).forEach(function (entry) {
+      var li = document.createElement("li");
+      var a = document.createElement("a");
+      a.href = root + entry.url;
+      a.textContent = entry.title + " (" + entry.id + ")";
+      li.appendChild(a);
+      results.appendChild(li);
+    });
+    if (!results.firstChild) {
+      var none = document.createElement("li");
+      none.textContent = "No matches";
+      results.appendChild(none);
+    }
+  });
+})();
@@ -0,0 +1,196 @@
+//! A small Rust tokenizer for syntax highlighting.
+//!
+//! It only needs to cope with the sheets, so it works one line at a time:
+//! nothing in them (strings, comments) spans lines.
+
+use std::ops::Range;
+
+/// What a token is, for choosing a CSS class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Comment,
+    Attribute,
+    String,
+    Char,
+    Lifetime,
+    Number,
+    Keyword,
+    Macro,
+    Type,
+    /// A method call: `birthday` in `user.birthday()`.
+    Method,
+    Ident,
+    /// Whitespace and punctuation.
+    Plain,
+}
+
+impl Class {
+    /// The CSS class used by the site's stylesheet, if any.
+    pub fn css(self) -> Option<&'static str> {
+        Some(match self {
+            Class::Comment => "c",
+            Class::Attribute => "at",
+            Class::String | Class::Char => "s",
+            Class::Lifetime => "lt",
+            Class::Number => "n",
+            Class::Keyword => "k",
+            Class::Macro => "m",
+            Class::Type => "t",
+            Class::Method => "f",
+            Class::Ident | Class::Plain => return None,
+        })
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "dyn", "else", "enum", "false", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+    "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while",
+];
+
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f32", "f64",
+];
+
+/// Splits one line of Rust into classified byte ranges covering all of it.
+pub fn line_tokens(line: &str) -> Vec<(Class, Range<usize>)> {
+    let bytes = line.as_bytes();
+    let mut out: Vec<(Class, Range<usize>)> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let b = bytes[i];
+        let class = if b == b'/' && bytes.get(i + 1) == Some(&b'/') {
+            i = bytes.len();
+            Class::Comment
+        } else if b == b'#' && bytes.get(i + 1) == Some(&b'[') {
+            i = line[i..].find(']').map_or(bytes.len(), |n| i + n + 1);
+            Class::Attribute
+        } else if b == b'"' || (b == b'b' && bytes.get(i + 1) == Some(&b'"')) {
+            i += usize::from(b == b'b') + 1;
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(bytes.len());
+            Class::String
+        } else if b == b'\'' || (b == b'b' && bytes.get(i + 1) == Some(&b'\'')) {
+            let q = i + usize::from(b == b'b');
+            if bytes.get(q + 1) == Some(&b'\\') || bytes.get(q + 2) == Some(&b'\'') {
+                i = q + 2;
+                while i < bytes.len() && bytes[i] != b'\'' {
+                    i += 1;
+                }
+                i = (i + 1).min(bytes.len());
+                Class::Char
+            } else {
+                i = q + 1;
+                while i < bytes.len() && is_ident(bytes[i]) {
+                    i += 1;
+                }
+                Class::Lifetime
+            }
+        } else if b.is_ascii_digit() {
+            while i < bytes.len()
+                && (is_ident(bytes[i])
+                    || bytes[i] == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+            {
+                i += 1;
+            }
+            Class::Number
+        } else if is_ident(b) {
+            while i < bytes.len() && is_ident(bytes[i]) {
+                i += 1;
+            }
+            let word = &line[start..i];
+            if bytes.get(i) == Some(&b'!') && bytes.get(i + 1) != Some(&b'=') {
+                i += 1;
+                Class::Macro
+            } else if KEYWORDS.contains(&word) {
+                Class::Keyword
+            } else if PRIMITIVES.contains(&word) || word.starts_with(|c: char| c.is_uppercase()) {
+                Class::Type
+            } else if start > 0 && bytes[start - 1] == b'.' && bytes.get(i) == Some(&b'(') {
+                Class::Method
+            } else {
+                Class::Ident
+            }
+        } else {
+            i += line[i..].chars().next().map_or(1, char::len_utf8);
+            Class::Plain
+        };
+        // Merge runs of punctuation and whitespace.
+        match out.last_mut() {
+            Some((Class::Plain, range)) if class == Class::Plain => range.end = i,
+            _ => out.push((class, start..i)),
+        }
+    }
+    out
+}
+
+fn is_ident(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(line: &str) -> Vec<(Class, &str)> {
+        line_tokens(line)
+            .into_iter()
+            .filter(|(c, _)| *c != Class::Plain)
+            .map(|(c, r)| (c, &line[r]))
+            .collect()
+    }
+
+    #[test]
+    fn classifies_a_demo_line() {
+        assert_eq!(
+            classes("    let big = 1_000_000u64;  // underscores ok"),
+            [
+                (Class::Keyword, "let"),
+                (Class::Ident, "big"),
+                (Class::Number, "1_000_000u64"),
+                (Class::Comment, "// underscores ok"),
+            ]
+        );
+        assert_eq!(
+            classes("user.birthday(); println!(\"{x}\", 'a', b'A');"),
+            [
+                (Class::Ident, "user"),
+                (Class::Method, "birthday"),
+                (Class::Macro, "println!"),
+                (Class::String, "\"{x}\""),
+                (Class::Char, "'a'"),
+                (Class::Char, "b'A'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn lifetimes_types_and_attributes() {
+        assert_eq!(
+            classes("#[derive(Debug)] fn f<'a>(s: &'a str) -> User"),
+            [
+                (Class::Attribute, "#[derive(Debug)]"),
+                (Class::Keyword, "fn"),
+                (Class::Ident, "f"),
+                (Class::Lifetime, "'a"),
+                (Class::Ident, "s"),
+                (Class::Lifetime, "'a"),
+                (Class::Type, "str"),
+                (Class::Type, "User"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_cover_the_whole_line() {
+        let line = "    let s: &str = \"héllo\"; // ünïcode";
+        let tokens = line_tokens(line);
+        let joined: String = tokens.iter().map(|(_, r)| &line[r.clone()]).collect();
+        assert_eq!(joined, line);
+    }
+}
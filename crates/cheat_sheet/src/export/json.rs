@@ -0,0 +1,378 @@
+//! Structured export of both sheets, for tools that want the sheet as data.
+//!
+//! Every [`Span`] is a byte range and 1-based line/column position in the
+//! section's module file (`file`, relative to the workspace root), so an
+//! editor can jump straight to a snippet, comment or helper item.
+
+use serde::Serialize;
+
+use crate::outline::{self, Block, Item, ItemKind, LineKind};
+use crate::registry::{self, Section, Sheet};
+
+/// Bumped when the shape of the export changes incompatibly.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Export {
+    pub version: u32,
+    pub sheets: Vec<SheetDoc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SheetDoc {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub sections: Vec<SectionDoc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionDoc {
+    pub id: &'static str,
+    pub number: u32,
+    pub title: &'static str,
+    /// The `// ====` banner text.
+    pub banner: &'static str,
+    pub tags: &'static [&'static str],
+    pub file: &'static str,
+    /// Contiguous runs of demo code, commented-out mistakes included.
+    pub snippets: Vec<Snippet>,
+    /// Comment-only lines and trailing comments of the demo.
+    pub comments: Vec<Comment>,
+    /// Commented-out lines that claim a compiler error.
+    pub mistakes: Vec<Mistake>,
+    /// Helper items defined in this section's module.
+    pub defines: Vec<ItemDoc>,
+    /// Helper items of the same sheet that the demo refers to.
+    pub uses: Vec<ItemRef>,
+    /// What `run()` prints, from the checked-in snapshot.
+    pub expected_output: &'static str,
+}
+
+/// A byte range plus 1-based line and column (in bytes) of both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Snippet {
+    pub span: Span,
+    /// The source text of `span`, verbatim.
+    pub code: &'static str,
+    /// `//=>` output annotations in the snippet, in order.
+    pub output: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentKind {
+    /// A line of its own: `// Copy vs Move`.
+    Line,
+    /// After code on the same line: `let x = 5; // immutable`.
+    Trailing,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Comment {
+    /// Span of the comment text, without the `//`.
+    pub span: Span,
+    pub kind: CommentKind,
+    pub text: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Mistake {
+    pub span: Span,
+    pub code: &'static str,
+    /// The claimed error code, e.g. `E0382`.
+    pub error: Option<&'static str>,
+    pub note: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKindDoc {
+    Fn,
+    Struct,
+    Enum,
+    Trait,
+    Impl,
+    Macro,
+}
+
+impl From<ItemKind> for ItemKindDoc {
+    fn from(kind: ItemKind) -> Self {
+        match kind {
+            ItemKind::Fn => ItemKindDoc::Fn,
+            ItemKind::Struct => ItemKindDoc::Struct,
+            ItemKind::Enum => ItemKindDoc::Enum,
+            ItemKind::Trait => ItemKindDoc::Trait,
+            ItemKind::Impl => ItemKindDoc::Impl,
+            ItemKind::Macro => ItemKindDoc::Macro,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemDoc {
+    pub kind: ItemKindDoc,
+    pub name: &'static str,
+    /// `fn add`, `impl Speak for i32`, ...
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trait_name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<&'static str>,
+    pub span: Span,
+    pub source: &'static str,
+}
+
+/// A pointer to a helper item defined in (possibly) another section.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemRef {
+    pub label: String,
+    /// The defining section's ID, or `shared` for the shared module.
+    pub section: &'static str,
+    pub file: &'static str,
+    pub span: Span,
+}
+
+/// Builds the export model for both sheets.
+pub fn model() -> Export {
+    Export {
+        version: FORMAT_VERSION,
+        sheets: [Sheet::Basics, Sheet::Types]
+            .into_iter()
+            .map(sheet_doc)
+            .collect(),
+    }
+}
+
+/// The model as pretty-printed JSON.
+pub fn render() -> String {
+    serde_json::to_string_pretty(&model()).expect("the export model always serializes")
+}
+
+fn sheet_doc(sheet: Sheet) -> SheetDoc {
+    let items = outline::sheet_items(sheet);
+    SheetDoc {
+        name: sheet.name(),
+        title: sheet.title(),
+        sections: registry::sheet(sheet)
+            .map(|s| section_doc(s, &items))
+            .collect(),
+    }
+}
+
+fn section_doc(section: &'static Section, sheet_items: &[Item]) -> SectionDoc {
+    let source = section.source;
+    let outline = outline::outline(section);
+    let mut snippets = Vec::new();
+    let mut comments = Vec::new();
+    let mut mistakes = Vec::new();
+
+    for block in &outline.blocks {
+        let lines = match block {
+            Block::Text(lines) | Block::Code(lines) => lines,
+        };
+        if let Block::Code(lines) = block {
+            let (first, last) = (lines[0].span, lines[lines.len() - 1].span);
+            let span = span(source, first.start, last.end);
+            snippets.push(Snippet {
+                span,
+                code: &source[span.start..span.end],
+                output: lines
+                    .iter()
+                    .flat_map(|l| match &l.kind {
+                        LineKind::Code { output, .. } => output.as_slice(),
+                        _ => &[],
+                    })
+                    .copied()
+                    .collect(),
+            });
+        }
+        for line in lines {
+            match &line.kind {
+                LineKind::Comment(text) => comments.push(Comment {
+                    span: sub_span(source, text),
+                    kind: CommentKind::Line,
+                    text,
+                }),
+                LineKind::Code {
+                    comment: Some(text),
+                    ..
+                } => comments.push(Comment {
+                    span: sub_span(source, text),
+                    kind: CommentKind::Trailing,
+                    text,
+                }),
+                LineKind::Code { .. } => {}
+                LineKind::Mistake { code, note } => mistakes.push(Mistake {
+                    span: span(source, line.span.start, line.span.end),
+                    code,
+                    error: crate::compile_fail::error_code(note),
+                    note,
+                }),
+            }
+        }
+    }
+
+    SectionDoc {
+        id: section.id,
+        number: section.number,
+        title: section.title,
+        banner: outline.banner,
+        tags: section.tags,
+        file: section.file,
+        snippets,
+        comments,
+        mistakes,
+        defines: outline
+            .items
+            .iter()
+            .map(|item| ItemDoc {
+                kind: item.kind.into(),
+                name: item.name,
+                label: item.label(),
+                trait_name: item.trait_name,
+                methods: item.methods.clone(),
+                span: span(source, item.span.start, item.span.end),
+                source: item.source,
+            })
+            .collect(),
+        uses: outline
+            .uses(sheet_items)
+            .into_iter()
+            .map(|item| {
+                let (file, source) =
+                    registry::home(item.section).expect("item from a known module");
+                ItemRef {
+                    label: item.label(),
+                    section: item.section,
+                    file,
+                    span: span(source, item.span.start, item.span.end),
+                }
+            })
+            .collect(),
+        expected_output: section.output,
+    }
+}
+
+/// Span of `sub`, which must be a slice of `source`.
+fn sub_span(source: &str, sub: &str) -> Span {
+    let start = sub.as_ptr() as usize - source.as_ptr() as usize;
+    span(source, start, start + sub.len())
+}
+
+fn span(source: &str, start: usize, end: usize) -> Span {
+    let (line, column) = position(source, start);
+    let (end_line, end_column) = position(source, end);
+    Span {
+        start,
+        end,
+        line,
+        column,
+        end_line,
+        end_column,
+    }
+}
+
+/// 1-based line and byte column of `offset`.
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = offset - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(id: &str) -> SectionDoc {
+        model()
+            .sheets
+            .into_iter()
+            .flat_map(|s| s.sections)
+            .find(|s| s.id == id)
+            .unwrap()
+    }
+
+    #[test]
+    fn positions_are_one_based() {
+        assert_eq!(position("ab\ncd", 0), (1, 1));
+        assert_eq!(position("ab\ncd", 2), (1, 3));
+        assert_eq!(position("ab\ncd", 3), (2, 1));
+        assert_eq!(position("ab\ncd", 5), (2, 3));
+    }
+
+    #[test]
+    fn comments_mistakes_and_helpers() {
+        let doc = section("variables");
+        let immutable = doc.comments.iter().find(|c| c.text == "immutable").unwrap();
+        assert_eq!(immutable.kind, CommentKind::Trailing);
+        assert_eq!((immutable.span.line, immutable.span.column), (7, 33));
+
+        let doc = section("ownership");
+        assert_eq!(doc.mistakes.len(), 2);
+        assert_eq!(doc.mistakes[1].error, Some("E0382"));
+        assert!(doc
+            .comments
+            .iter()
+            .any(|c| c.kind == CommentKind::Line && c.text == "Copy vs Move"));
+
+        let doc = section("structs");
+        let uses: Vec<_> = doc.uses.iter().map(|u| u.label.as_str()).collect();
+        assert_eq!(uses, ["struct User", "impl User"]);
+        assert_eq!(doc.defines[1].methods, ["new", "birthday", "greet"]);
+        assert!(doc.expected_output.starts_with("user: User {"));
+
+        let doc = section("enums");
+        let msg = &doc.uses[0];
+        assert_eq!(
+            (msg.label.as_str(), msg.section, msg.file),
+            ("enum Msg", "shared", registry::SHARED_FILE)
+        );
+        assert!(registry::SHARED_SOURCE[msg.span.start..]
+            .starts_with("#[derive(Debug, Clone, PartialEq, Eq)]"));
+    }
+
+    #[test]
+    fn every_span_matches_the_file_on_disk() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        for sheet in model().sheets {
+            for doc in sheet.sections {
+                let file = std::fs::read_to_string(root.join(doc.file)).unwrap();
+                let check = |span: Span, text: &str| {
+                    assert_eq!(&file[span.start..span.end], text, "{}", doc.id);
+                    assert_eq!(span, super::span(&file, span.start, span.end), "{}", doc.id);
+                };
+                doc.snippets.iter().for_each(|s| check(s.span, s.code));
+                doc.comments.iter().for_each(|c| check(c.span, c.text));
+                doc.defines.iter().for_each(|i| check(i.span, i.source));
+                for m in &doc.mistakes {
+                    assert!(file[m.span.start..m.span.end].contains(m.code));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn snippets_are_verbatim_source() {
+        let doc = section("vec");
+        let first = &doc.snippets[0];
+        assert_eq!(
+            first.code,
+            "    let mut nums = vec![1, 2, 3];\n    nums.push(4);"
+        );
+        assert_eq!((first.span.line, first.span.end_line), (8, 9));
+        let json = render();
+        assert!(json.contains("\"version\": 1"));
+        assert!(json.contains("\"kind\": \"trailing\""));
+    }
+}
@@ -0,0 +1,188 @@
+//! Markdown export of both sheets.
+//!
+//! Each `// ====` banner becomes a heading, contiguous code becomes a fenced
+//! `rust` block, and trailing comments become a table of notes under it.
+//! `//=>` annotations are collected into an output block, and the helper
+//! items a section uses (`add`, `User`, `Msg`, ...) are listed after it.
+
+use std::fmt::Write;
+
+use crate::outline::{self, Block, Item, Line, LineKind, Outline};
+use crate::registry::{self, Sheet};
+
+/// Renders both sheets as one Markdown document.
+pub fn render() -> String {
+    let mut out = String::new();
+    for (i, sheet) in [Sheet::Basics, Sheet::Types].into_iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&render_sheet(sheet));
+    }
+    out
+}
+
+/// Renders one sheet, with a top-level heading.
+pub fn render_sheet(sheet: Sheet) -> String {
+    let items = outline::sheet_items(sheet);
+    let mut out = format!("# {}\n", sheet.title());
+    for section in registry::sheet(sheet) {
+        let outline = outline::outline(section);
+        out.push('\n');
+        render_section(&mut out, &outline, &outline.uses(&items));
+    }
+    out
+}
+
+fn render_section(out: &mut String, outline: &Outline, helpers: &[&Item]) {
+    let source = outline.section.source;
+    let _ = writeln!(out, "## {}\n", outline.banner);
+    let _ = writeln!(
+        out,
+        "_`{}` · {}_",
+        outline.section.id,
+        outline.section.tags.join(", ")
+    );
+    for block in &outline.blocks {
+        out.push('\n');
+        match block {
+            Block::Text(lines) => {
+                let text: Vec<&str> = lines
+                    .iter()
+                    .filter_map(|l| match l.kind {
+                        LineKind::Comment(text) => Some(text),
+                        _ => None,
+                    })
+                    .collect();
+                let _ = writeln!(out, "{}", text.join("  \n"));
+            }
+            Block::Code(lines) => render_code(out, source, lines),
+        }
+    }
+    if !helpers.is_empty() {
+        let names: Vec<String> = helpers.iter().map(|i| format!("`{}`", i.label())).collect();
+        let _ = writeln!(out, "\n**Helpers:** {}\n", names.join(", "));
+        out.push_str("```rust\n");
+        for (i, item) in helpers.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let _ = writeln!(out, "{}", item.source);
+        }
+        out.push_str("```\n");
+    }
+}
+
+fn render_code(out: &mut String, source: &str, lines: &[Line]) {
+    let mut notes = Vec::new();
+    let mut output: Vec<&str> = Vec::new();
+    out.push_str("```rust\n");
+    for line in lines {
+        let indent = line.span.text(source).len() - line.span.text(source).trim_start().len();
+        let pad = " ".repeat(indent.saturating_sub(4));
+        match &line.kind {
+            LineKind::Code {
+                code,
+                comment,
+                output: printed,
+            } => {
+                let _ = writeln!(out, "{pad}{code}");
+                if let Some(comment) = comment {
+                    notes.push((*code, comment.to_string()));
+                }
+                output.extend(printed);
+            }
+            LineKind::Mistake { code, note } => {
+                let _ = writeln!(out, "{pad}// {code}");
+                notes.push((*code, format!("does not compile: {note}")));
+            }
+            LineKind::Comment(text) => {
+                let _ = writeln!(out, "{pad}// {text}");
+            }
+        }
+    }
+    out.push_str("```\n");
+
+    if !notes.is_empty() {
+        out.push_str("\n| Code | Note |\n| --- | --- |\n");
+        for (code, note) in notes {
+            let _ = writeln!(out, "| {} | {} |", code_cell(code), escape_cell(&note));
+        }
+    }
+    if !output.is_empty() {
+        out.push_str("\nOutput:\n\n```text\n");
+        for line in output {
+            let _ = writeln!(out, "{line}");
+        }
+        out.push_str("```\n");
+    }
+}
+
+fn code_cell(code: &str) -> String {
+    let code = escape_cell(code);
+    if code.contains('`') {
+        format!("`` {code} ``")
+    } else {
+        format!("`{code}`")
+    }
+}
+
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(id: &str) -> String {
+        let section = registry::find(id).unwrap();
+        let items = outline::sheet_items(section.sheet);
+        let outline = outline::outline(section);
+        let mut out = String::new();
+        render_section(&mut out, &outline, &outline.uses(&items));
+        out
+    }
+
+    #[test]
+    fn banners_become_headings() {
+        let md = render();
+        assert!(md.starts_with("# Rust cheat sheet\n"));
+        assert!(md.contains("\n# Rust types cheat sheet\n"));
+        assert!(md.contains("\n## 4) Ownership + Borrowing (OWNERSHIP)\n"));
+        assert!(md.contains("\n## ARRAYS & SLICES\n"));
+        assert_eq!(md.matches("\n## ").count(), registry::registry().len());
+    }
+
+    #[test]
+    fn trailing_comments_become_table_cells() {
+        let md = section("variables");
+        assert!(md.contains("```rust\nlet x = 5;\nlet mut y: i32 = 10;\ny += 1;\n```\n"));
+        assert!(md.contains("| `let x = 5;` | immutable |\n"));
+        assert!(md.contains("Output:\n\n```text\nx=5, y=11, MAX=99, big=1000000\n```\n"));
+    }
+
+    #[test]
+    fn comments_and_mistakes() {
+        let md = section("ownership");
+        assert!(md.contains("\nCopy vs Move\n"));
+        assert!(md.contains("// println!(\"{:?}\", v1);\n"));
+        assert!(md.contains(
+            "| `println!(\"{:?}\", v1);` | does not compile: error[E0382]: use of moved value |"
+        ));
+        let md = section("patterns");
+        assert!(md.contains("    4 | 5 => println!(\"4 or 5\"),\n"));
+    }
+
+    #[test]
+    fn helpers_are_listed_under_the_sections_using_them() {
+        let md = section("lifetimes");
+        assert!(md.contains("**Helpers:** `fn pick_longer`"));
+        assert!(md.contains("pub fn pick_longer<'a>(a: &'a str, b: &'a str) -> &'a str {"));
+        assert!(section("structs").contains("**Helpers:** `struct User`, `impl User`"));
+        assert!(section("enums").contains("**Helpers:** `enum Msg`, `fn handle`"));
+        assert!(section("types/trait_object").contains("`trait Speak`"));
+        assert!(section("functions").contains("**Helpers:** `fn add`"));
+        assert!(!section("patterns").contains("**Helpers:**"));
+    }
+}
@@ -0,0 +1,7 @@
+//! Renderings of both sheets for use outside Rust.
+
+pub mod highlight;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod markdown;
+pub mod site;
@@ -0,0 +1,522 @@
+//! Static HTML site: one page per section, a tag index and client-side search.
+//!
+//! The stylesheet and script are embedded with `include_str!`, so the site
+//! needs no network access and can be opened straight from disk.
+//!
+//! ```text
+//! index.html              both sheets, section by section
+//! tags.html               OWNERSHIP, STRINGS, VEC, ... -> sections
+//! shared.html             items both sheets import (Msg, id, Speak)
+//! basics/ownership.html   highlighted source, output, helpers used
+//! types/vec.html
+//! assets/site.css, assets/site.js, assets/search-index.js
+//! ```
+//!
+//! Identifiers in the code link to the helper item they refer to, e.g.
+//! `user.birthday()` links to `impl User` on the Structs page.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use super::highlight::{self, Class};
+use crate::outline::{self, Item, ItemKind};
+use crate::registry::{self, Section, Sheet};
+
+pub const CSS: &str = include_str!("assets/site.css");
+pub const JS: &str = include_str!("assets/site.js");
+
+/// A file of the generated site.
+#[derive(Debug, Clone)]
+pub struct Page {
+    /// Path relative to the output directory, with `/` separators.
+    pub path: String,
+    pub contents: String,
+}
+
+/// Renders every page of the site.
+pub fn pages() -> Vec<Page> {
+    let links = Links::new();
+    let mut pages = vec![index_page(), tags_page(), shared_page(&links)];
+    pages.extend(registry::registry().iter().map(|s| section_page(s, &links)));
+    pages.push(Page {
+        path: "assets/site.css".into(),
+        contents: CSS.into(),
+    });
+    pages.push(Page {
+        path: "assets/site.js".into(),
+        contents: JS.into(),
+    });
+    pages.push(search_index());
+    pages
+}
+
+/// Writes the site into `dir`, creating it if needed. Returns the number of
+/// files written.
+pub fn build(dir: &Path) -> io::Result<usize> {
+    let pages = pages();
+    for page in &pages {
+        let path = dir.join(&page.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, &page.contents)?;
+    }
+    Ok(pages.len())
+}
+
+/// Root-relative URL of a section's page.
+pub fn section_url(section: &Section) -> String {
+    format!("{}/{}.html", section.sheet.name(), section.module())
+}
+
+fn item_anchor(item: &Item) -> String {
+    let label: String = item
+        .label()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("item-{label}")
+}
+
+fn item_url(item: &Item) -> String {
+    if item.section == registry::SHARED {
+        return format!("{SHARED_URL}#{}", item_anchor(item));
+    }
+    let section = registry::find(item.section).expect("item from a registered section");
+    format!("{}#{}", section_url(section), item_anchor(item))
+}
+
+const SHARED_URL: &str = "shared.html";
+
+/// Where identifiers link to, per sheet.
+struct Links {
+    items: HashMap<Sheet, Vec<Item>>,
+    names: HashMap<Sheet, HashMap<&'static str, String>>,
+    methods: HashMap<Sheet, HashMap<&'static str, String>>,
+}
+
+impl Links {
+    fn new() -> Self {
+        let mut links = Links {
+            items: HashMap::new(),
+            names: HashMap::new(),
+            methods: HashMap::new(),
+        };
+        for sheet in [Sheet::Basics, Sheet::Types] {
+            let items = outline::sheet_items(sheet);
+            let names = links.names.entry(sheet).or_default();
+            for item in items.iter().filter(|i| i.kind != ItemKind::Impl) {
+                names.entry(item.name).or_insert_with(|| item_url(item));
+            }
+            // The receiver's type isn't known, so a method is only linked when
+            // one item defines it: `.speak()` could be any Speak impl, and
+            // `"7".parse()` isn't `Plugin::parse`.
+            let mut defined: HashMap<&str, Vec<&Item>> = HashMap::new();
+            for item in &items {
+                for m in &item.methods {
+                    defined.entry(m).or_default().push(item);
+                }
+            }
+            let methods = links.methods.entry(sheet).or_default();
+            for (m, defs) in defined {
+                if let [item] = defs[..] {
+                    methods.insert(m, item_url(item));
+                }
+            }
+            links.items.insert(sheet, items);
+        }
+        links
+    }
+
+    fn target(&self, sheet: Sheet, class: Class, word: &str) -> Option<&str> {
+        let map = match class {
+            Class::Method => &self.methods,
+            Class::Ident | Class::Type => &self.names,
+            _ => return None,
+        };
+        map.get(&sheet)?.get(word).map(String::as_str)
+    }
+}
+
+fn section_page(section: &'static Section, links: &Links) -> Page {
+    let root = "../";
+    let outline = outline::outline(section);
+
+    let mut body = String::new();
+    let _ = writeln!(body, "<h1>{}</h1>", escape(outline.banner));
+    body.push_str(&tag_links(section, root));
+    let _ = writeln!(
+        body,
+        "<p><code>cheat run {}</code> · <code>{}</code></p>",
+        escape(section.id),
+        escape(section.file)
+    );
+
+    source_html(
+        &mut body,
+        section.source,
+        &outline.items,
+        section.sheet,
+        links,
+        root,
+    );
+
+    let _ = writeln!(
+        body,
+        "<h2>Output</h2>\n<pre class=\"output\">{}</pre>",
+        escape(section.output)
+    );
+
+    let used = outline.uses(&links.items[&section.sheet]);
+    if !used.is_empty() {
+        body.push_str("<h2>Helpers used</h2>\n<ul>\n");
+        for item in used {
+            let _ = writeln!(
+                body,
+                "<li><a href=\"{root}{}\"><code>{}</code></a></li>",
+                escape(&item_url(item)),
+                escape(&item.label())
+            );
+        }
+        body.push_str("</ul>\n");
+    }
+
+    Page {
+        path: section_url(section),
+        contents: layout(section.title, root, Some(section.id), &body),
+    }
+}
+
+/// Highlighted, line-numbered `source`, with an anchor at each of `items`
+/// and identifiers linked to the items they name.
+fn source_html(
+    body: &mut String,
+    source: &str,
+    items: &[Item],
+    sheet: Sheet,
+    links: &Links,
+    root: &str,
+) {
+    let item_starts: BTreeMap<usize, &Item> = items.iter().map(|i| (i.span.line, i)).collect();
+    body.push_str("<pre class=\"source\"><code>");
+    for (i, line) in source.lines().enumerate() {
+        let n = i + 1;
+        let _ = write!(
+            body,
+            "<span class=\"line\" id=\"L{n}\"><a class=\"ln\" href=\"#L{n}\">{n}</a>"
+        );
+        if let Some(item) = item_starts.get(&n) {
+            let _ = write!(body, "<a id=\"{}\"></a>", item_anchor(item));
+        }
+        for (class, range) in highlight::line_tokens(line) {
+            let text = escape(&line[range.clone()]);
+            let href = links.target(sheet, class, &line[range]);
+            match (href, class.css()) {
+                (Some(href), css) => {
+                    let _ = write!(body, "<a href=\"{root}{}\"", escape(href));
+                    if let Some(css) = css {
+                        let _ = write!(body, " class=\"{css}\"");
+                    }
+                    let _ = write!(body, ">{text}</a>");
+                }
+                (None, Some(css)) => {
+                    let _ = write!(body, "<span class=\"{css}\">{text}</span>");
+                }
+                (None, None) => body.push_str(&text),
+            }
+        }
+        body.push_str("</span>");
+    }
+    body.push_str("</code></pre>\n");
+}
+
+/// The shared module's items, which section pages link to.
+fn shared_page(links: &Links) -> Page {
+    let mut body = String::from(
+        "<h1>Shared items</h1>\n<p>Both sheets import these instead of defining their own.</p>\n",
+    );
+    let _ = writeln!(
+        body,
+        "<p><code>{}</code></p>",
+        escape(registry::SHARED_FILE)
+    );
+    // Shared items come first in every sheet's links, so either sheet works.
+    source_html(
+        &mut body,
+        registry::SHARED_SOURCE,
+        &outline::shared_items(),
+        Sheet::Basics,
+        links,
+        "",
+    );
+    Page {
+        path: SHARED_URL.into(),
+        contents: layout("Shared items", "", None, &body),
+    }
+}
+
+fn index_page() -> Page {
+    let mut body = String::from(
+        "<h1>Rust cheat sheet</h1>\n\
+         <p>Runnable Rust, one section per page. Browse by sheet below, by \
+         <a href=\"tags.html\">tag</a>, or search from the sidebar.</p>\n",
+    );
+    for sheet in [Sheet::Basics, Sheet::Types] {
+        let _ = writeln!(body, "<h2>{}</h2>\n<ol>", escape(sheet.title()));
+        for section in registry::sheet(sheet) {
+            let _ = writeln!(
+                body,
+                "<li><a href=\"{}\">{}</a> {}</li>",
+                section_url(section),
+                escape(section.title),
+                tag_links(section, "").trim_end()
+            );
+        }
+        body.push_str("</ol>\n");
+    }
+    Page {
+        path: "index.html".into(),
+        contents: layout("Rust cheat sheet", "", None, &body),
+    }
+}
+
+fn tags_page() -> Page {
+    let mut tags: BTreeMap<&str, Vec<&Section>> = BTreeMap::new();
+    for section in registry::registry() {
+        for tag in section.tags {
+            tags.entry(tag).or_default().push(section);
+        }
+    }
+    let mut body = String::from("<h1>Tags</h1>\n");
+    for (tag, sections) in tags {
+        let _ = writeln!(body, "<h2 id=\"tag-{tag}\">{tag}</h2>\n<ul>");
+        for section in sections {
+            let _ = writeln!(
+                body,
+                "<li><a href=\"{}\">{}</a> <small>({})</small></li>",
+                section_url(section),
+                escape(section.title),
+                escape(section.id)
+            );
+        }
+        body.push_str("</ul>\n");
+    }
+    Page {
+        path: "tags.html".into(),
+        contents: layout("Tags", "", None, &body),
+    }
+}
+
+fn search_index() -> Page {
+    let mut js = String::from("window.CHEAT_INDEX = [\n");
+    for section in registry::registry() {
+        let tags: Vec<String> = section.tags.iter().map(|t| js_string(t)).collect();
+        let _ = writeln!(
+            js,
+            "  {{\"id\": {}, \"title\": {}, \"url\": {}, \"tags\": [{}], \"text\": {}}},",
+            js_string(section.id),
+            js_string(section.title),
+            js_string(&section_url(section)),
+            tags.join(", "),
+            js_string(section.source)
+        );
+    }
+    js.push_str("];\n");
+    Page {
+        path: "assets/search-index.js".into(),
+        contents: js,
+    }
+}
+
+fn tag_links(section: &Section, root: &str) -> String {
+    let tags: Vec<String> = section
+        .tags
+        .iter()
+        .map(|t| format!("<a href=\"{root}tags.html#tag-{t}\">{t}</a>"))
+        .collect();
+    format!("<span class=\"tags\">{}</span>\n", tags.join(""))
+}
+
+fn layout(title: &str, root: &str, current: Option<&str>, body: &str) -> String {
+    let mut nav = String::new();
+    let _ = writeln!(nav, "<h1><a href=\"{root}index.html\">cheat sheet</a></h1>");
+    nav.push_str("<input id=\"search\" type=\"search\" placeholder=\"Search sections\" autocomplete=\"off\">\n<ul id=\"results\"></ul>\n");
+    for sheet in [Sheet::Basics, Sheet::Types] {
+        let _ = writeln!(nav, "<h2>{}</h2>\n<ol>", escape(sheet.title()));
+        for section in registry::sheet(sheet) {
+            let class = if current == Some(section.id) {
+                " class=\"current\""
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                nav,
+                "<li{class}><a href=\"{root}{}\">{}</a></li>",
+                section_url(section),
+                escape(section.title)
+            );
+        }
+        nav.push_str("</ol>\n");
+    }
+    let _ = writeln!(nav, "<h2><a href=\"{root}tags.html\">Tags</a></h2>");
+    let _ = writeln!(
+        nav,
+        "<h2><a href=\"{root}{SHARED_URL}\">Shared items</a></h2>"
+    );
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html lang=\"en\">\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{} · Rust cheat sheet</title>\n\
+         <link rel=\"stylesheet\" href=\"{root}assets/site.css\">\n\
+         </head>\n\
+         <body data-root=\"{root}\">\n\
+         <nav>\n{nav}</nav>\n\
+         <main>\n{body}</main>\n\
+         <script src=\"{root}assets/search-index.js\"></script>\n\
+         <script src=\"{root}assets/site.js\"></script>\n\
+         </body>\n\
+         </html>\n",
+        escape(title)
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// A JavaScript (and JSON) string literal.
+fn js_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            // Keep `</script>` and friends out of the inline data.
+            '<' => out.push_str("\\u003c"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(path: &str) -> Page {
+        pages()
+            .into_iter()
+            .find(|p| p.path == path)
+            .unwrap_or_else(|| panic!("no page {path}"))
+    }
+
+    #[test]
+    fn one_page_per_section_plus_index_tags_and_assets() {
+        let paths: Vec<String> = pages().into_iter().map(|p| p.path).collect();
+        assert_eq!(paths.len(), registry::registry().len() + 6);
+        for path in [
+            "index.html",
+            "tags.html",
+            "shared.html",
+            "basics/ownership.html",
+            "types/vec.html",
+        ] {
+            assert!(paths.iter().any(|p| p == path), "{path}");
+        }
+    }
+
+    #[test]
+    fn code_is_highlighted_and_escaped() {
+        let html = page("basics/lifetimes.html").contents;
+        assert!(html.contains("<span class=\"k\">let</span>"));
+        assert!(html.contains("&amp;<span class=\"lt\">'a</span>"));
+        assert!(html.contains("&amp;"));
+        assert!(!html.contains("&'a str"));
+    }
+
+    #[test]
+    fn usages_link_to_definitions() {
+        let html = page("basics/structs.html").contents;
+        assert!(html.contains("<a id=\"item-impl-User\"></a>"));
+        assert!(html.contains(
+            "<a href=\"../basics/structs.html#item-impl-User\" class=\"f\">birthday</a>"
+        ));
+        assert!(html
+            .contains("<a href=\"../basics/structs.html#item-struct-User\" class=\"t\">User</a>"));
+
+        let html = page("basics/functions.html").contents;
+        assert!(html.contains("<a href=\"../basics/functions.html#item-fn-add\">add</a>"));
+        // Both sheets' `Msg` is the shared one.
+        for path in ["types/struct_enum.html", "basics/enums.html"] {
+            let html = page(path).contents;
+            assert!(
+                html.contains("<a href=\"../shared.html#item-enum-Msg\" class=\"t\">Msg</a>"),
+                "{path}"
+            );
+        }
+        let html = page("shared.html").contents;
+        assert!(html.contains("<a id=\"item-impl-Speak-for-String\"></a>"));
+        assert!(html.contains("<a href=\"shared.html#item-trait-Speak\" class=\"t\">Speak</a>"));
+    }
+
+    #[test]
+    fn methods_defined_more_than_once_are_not_linked() {
+        // `"123".parse()` is str's, not `Plugin::parse`; `e.source()` is
+        // `Error::source`, not whichever impl came first.
+        for (path, method) in [
+            ("types/conversions.html", "parse"),
+            ("basics/errors.html", "source"),
+            ("basics/traits.html", "speak"),
+        ] {
+            let html = page(path).contents;
+            assert!(
+                html.contains(&format!("<span class=\"f\">{method}</span>")),
+                "{path}"
+            );
+            assert!(!html.contains(&format!(">{method}</a>")), "{path}");
+        }
+    }
+
+    #[test]
+    fn tag_index_and_search_data() {
+        let html = page("tags.html").contents;
+        assert!(html.contains("<h2 id=\"tag-OWNERSHIP\">OWNERSHIP</h2>"));
+        assert!(html.contains("<a href=\"types/clones.html\">"));
+        let js = page("assets/search-index.js").contents;
+        assert!(js.starts_with("window.CHEAT_INDEX = ["));
+        assert!(js.contains("\"id\": \"types/vec\""));
+        assert!(!js.contains("</"));
+    }
+
+    #[test]
+    fn self_contained() {
+        for page in pages() {
+            assert!(!page.contents.contains("http://"), "{}", page.path);
+            assert!(!page.contents.contains("https://"), "{}", page.path);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! A "cheat sheet" written as runnable Rust.
+//!
+//! The crate is split into two sheets, each made of one module per section:
+//!
+//! - [`basics`]: the numbered walk-through (variables, functions, ownership,
+//!   strings, collections, structs, enums, results, traits, lifetimes, patterns,
+//!   a worked command interpreter, custom error types, `macro_rules!` macros,
+//!   and with the `derive` feature `#[derive(Speak)]` from the `cheat_derive`
+//!   proc-macro crate).
+//! - [`types`]: a tour of the type system (primitives, strings, slices, tuples,
+//!   arrays, vecs, options/results, refs, structs/enums, generics/traits,
+//!   conversions, operator overloading on a generic `Point<T>`, a trait-object
+//!   plugin registry, and with the `serde` feature JSON/TOML round-trips and
+//!   enum representations).
+//!
+//! Every section module has a `run()` entry point that prints its demo, and
+//! each sheet has a `run()` that plays all of its sections in order. Items
+//! both sheets use (`Msg`, `id`, `Speak`) live once, in [`shared`]. The
+//! [`registry`] lists every section with its ID, title and tags, so tools can
+//! look sections up instead of grepping, and [`compile_fail`] checks the
+//! commented-out error lines against the real compiler. Each section's stdout
+//! is checked in under `snapshots/` and compared by [`snapshot`], and the
+//! `//=>` comments next to demo lines are verified by [`annotations`].
+//! [`explain`] maps common rustc error codes to the demos that show the rule,
+//! and [`quiz`] turns the annotations and error lines into questions whose
+//! results [`progress`] schedules for review. [`exercise`] hands out helpers
+//! with `todo!()` bodies and grades them with hidden tests, run by [`runner`]
+//! with a time limit and an output cap (and no isolation). [`search`] ranks
+//! sections for a query, tolerating typos.
+//!
+//! [`outline`] parses a section module into banner, demo lines and helper
+//! items; [`export`] renders both sheets from that for wikis and tools (JSON
+//! with source spans needs the `serde` feature, which also derives
+//! `Serialize`/`Deserialize` for `Msg`, `User` and `Point`; the `derive` feature
+//! derives `Speak` for `Msg` and `User`).
+
+// `#[derive(Speak)]` names the trait `::cheat_sheet::shared::Speak`; this
+// makes that path resolve inside the crate too.
+extern crate self as cheat_sheet;
+
+pub mod annotations;
+// The sheets are hand-aligned (trailing comments line up); keep rustfmt out.
+#[rustfmt::skip]
+pub mod basics;
+pub mod compile_fail;
+pub mod exercise;
+pub mod explain;
+pub mod export;
+pub mod outline;
+pub mod progress;
+pub mod quiz;
+pub mod registry;
+pub mod runner;
+pub mod search;
+pub mod shared;
+pub mod snapshot;
+#[rustfmt::skip]
+pub mod types;
+
+pub use registry::{registry, Section, Sheet};
+
+/// Runs both sheets, basics first.
+pub fn run_all() {
+    basics::run();
+    types::run();
+}
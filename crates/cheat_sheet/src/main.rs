@@ -0,0 +1,85 @@
+// Runs every section of both cheat sheets, in order, or just the ones named.
+// `cargo run -p cheat_sheet`
+// `cargo run -p cheat_sheet -- ownership types/vec`
+// `cargo run -p cheat_sheet -- --script < moves.txt`  (the COMMANDS interpreter)
+//
+// `main` returns `Exit`, which implements `Termination`, so `?` works in
+// `try_main` and each kind of failure gets its own exit code. The
+// `cheat_sheet_boxed` binary does the same with `Result<(), Box<dyn Error>>`.
+
+use std::fmt;
+use std::io;
+use std::process::{ExitCode, Termination};
+
+use cheat_sheet::basics::{ScriptError, State};
+use cheat_sheet::registry;
+
+fn main() -> Exit {
+    Exit(try_main())
+}
+
+fn try_main() -> Result<(), Failure> {
+    let ids: Vec<String> = std::env::args().skip(1).collect();
+    if ids == ["--script"] {
+        let mut state = State::default();
+        state.run_script(io::stdin().lock())?; // ScriptError -> Failure via From
+        println!("{state:?}");
+        return Ok(());
+    }
+    if ids.is_empty() {
+        cheat_sheet::run_all();
+        return Ok(());
+    }
+    for id in &ids {
+        let section = registry::find(id).ok_or_else(|| Failure::UnknownSection(id.clone()))?;
+        (section.run)();
+    }
+    Ok(())
+}
+
+enum Failure {
+    UnknownSection(String),
+    Script(ScriptError),
+}
+
+impl Failure {
+    // The sysexits.h codes: usage, bad input data, I/O error.
+    fn exit_code(&self) -> u8 {
+        match self {
+            Failure::UnknownSection(_) => 2,
+            Failure::Script(ScriptError::Parse { .. }) => 65,
+            Failure::Script(ScriptError::Io(_)) => 74,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::UnknownSection(id) => write!(f, "no section `{id}`"),
+            Failure::Script(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ScriptError> for Failure {
+    fn from(e: ScriptError) -> Self {
+        Failure::Script(e)
+    }
+}
+
+/// What `main` returns: std calls [`Termination::report`] on it to get the
+/// process's exit code.
+struct Exit(Result<(), Failure>);
+
+impl Termination for Exit {
+    fn report(self) -> ExitCode {
+        match self.0 {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(failure) => {
+                eprintln!("cheat_sheet: {failure}");
+                ExitCode::from(failure.exit_code())
+            }
+        }
+    }
+}
@@ -0,0 +1,905 @@
+//! Structure of a section module: its banner, demo lines and helper items.
+//!
+//! Section modules share one layout:
+//!
+//! ```text
+//! //! title                            <- module doc
+//!
+//! pub fn run() {
+//!     // =========================
+//!     // 4) Ownership + Borrowing      <- banner
+//!     // =========================
+//!     let a = 123i32;      // Copy     <- code + trailing comment
+//!     println!("{a}"); //=> 123        <- code + output annotation
+//!     // println!("{:?}", v1); // error[E0382]: ...   <- mistake
+//! }
+//!
+//! // Borrow immutably                 <- helper item, with its comments
+//! pub fn borrow_str(s: &String) { .. }
+//! ```
+//!
+//! [`outline`] splits that into [`Block`]s of demo lines and the [`Item`]s
+//! defined after `run()`, each with a [`Span`] into [`Section::source`].
+//! Items both sheets import from [`crate::shared`] come from
+//! [`shared_items`].
+
+use std::collections::HashSet;
+
+use crate::registry::{self, Section, Sheet};
+
+/// A byte range of a section's source, with the 1-based lines it covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub end_line: usize,
+}
+
+impl Span {
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start..self.end]
+    }
+}
+
+/// One line of the `run()` body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// The whole line, without its newline.
+    pub span: Span,
+    pub kind: LineKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineKind {
+    /// A comment-only line: `// Copy vs Move`.
+    Comment(&'static str),
+    /// A statement, its trailing comment and its `//=>` output annotations.
+    Code {
+        code: &'static str,
+        comment: Option<&'static str>,
+        output: Vec<&'static str>,
+    },
+    /// Commented-out code that names the error it would cause:
+    /// `// println!("{:?}", v1); // error[E0382]: use of moved value`.
+    Mistake {
+        code: &'static str,
+        note: &'static str,
+    },
+}
+
+/// Consecutive demo lines of one kind, split at blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// Comment-only lines.
+    Text(Vec<Line>),
+    /// Code lines, including commented-out mistakes.
+    Code(Vec<Line>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Fn,
+    Struct,
+    Enum,
+    Trait,
+    Impl,
+    /// A `macro_rules!` definition.
+    Macro,
+}
+
+/// A helper item defined after `run()`: `add`, `User`, `impl User`, ...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub kind: ItemKind,
+    /// The item's name; for impls, the self type (`User`, `i32`), or the
+    /// trait behind a trait object or reference (`Speak` for `dyn Speak`).
+    pub name: &'static str,
+    /// For impls on a trait object or reference, the self type as written
+    /// (`dyn Speak + Send + Sync`).
+    pub self_ty: Option<&'static str>,
+    /// The implemented trait, for trait impls, with any generic arguments
+    /// (`Speak`, `From<io::Error>`).
+    pub trait_name: Option<&'static str>,
+    /// Methods declared in a trait or impl.
+    pub methods: Vec<&'static str>,
+    /// ID of the section that defines it.
+    pub section: &'static str,
+    /// The item with its leading comments and attributes.
+    pub span: Span,
+    pub source: &'static str,
+}
+
+impl Item {
+    /// `fn add`, `struct User`, `impl Speak for i32`, `macro_rules! max`, ...
+    pub fn label(&self) -> String {
+        match (self.kind, self.trait_name) {
+            (ItemKind::Fn, _) => format!("fn {}", self.name),
+            (ItemKind::Struct, _) => format!("struct {}", self.name),
+            (ItemKind::Enum, _) => format!("enum {}", self.name),
+            (ItemKind::Trait, _) => format!("trait {}", self.name),
+            (ItemKind::Impl, Some(t)) => {
+                format!("impl {t} for {}", self.self_ty.unwrap_or(self.name))
+            }
+            (ItemKind::Impl, None) => format!("impl {}", self.self_ty.unwrap_or(self.name)),
+            (ItemKind::Macro, _) => format!("macro_rules! {}", self.name),
+        }
+    }
+}
+
+/// A parsed section module.
+#[derive(Debug, Clone)]
+pub struct Outline {
+    pub section: &'static Section,
+    /// The banner text, e.g. `4) Ownership + Borrowing (OWNERSHIP)`.
+    pub banner: &'static str,
+    pub blocks: Vec<Block>,
+    /// Items defined in this module, tests excluded.
+    pub items: Vec<Item>,
+}
+
+impl Outline {
+    /// Every code line of the demo, in order.
+    pub fn code_lines(&self) -> impl Iterator<Item = &Line> {
+        self.blocks.iter().flat_map(|b| match b {
+            Block::Code(lines) => lines.as_slice(),
+            Block::Text(_) => &[],
+        })
+    }
+
+    /// The items of `candidates` that the demo code refers to, by name or by
+    /// calling one of their methods.
+    pub fn uses<'a>(&self, candidates: &'a [Item]) -> Vec<&'a Item> {
+        let mut names = HashSet::new();
+        let mut calls = HashSet::new();
+        for line in self.code_lines() {
+            if let LineKind::Code { code, .. } = line.kind {
+                for ident in identifiers(code) {
+                    if ident.method {
+                        calls.insert(ident.text);
+                    } else {
+                        names.insert(ident.text);
+                    }
+                }
+            }
+        }
+        let called = |item: &Item| {
+            item.methods
+                .iter()
+                .any(|m| calls.contains(m) || names.contains(m))
+        };
+        candidates
+            .iter()
+            .filter(|item| match item.kind {
+                ItemKind::Impl => {
+                    called(item)
+                        && (names.contains(item.name)
+                            || item
+                                .trait_name
+                                .is_some_and(|t| names.contains(trait_ident(t))))
+                }
+                ItemKind::Trait => names.contains(item.name) || called(item),
+                _ => names.contains(item.name),
+            })
+            .collect()
+    }
+}
+
+/// Parses a section module.
+pub fn outline(section: &'static Section) -> Outline {
+    let lines: Vec<RawLine> = raw_lines(section.source).collect();
+    let run = lines
+        .iter()
+        .position(|l| l.text.trim_end() == "pub fn run() {")
+        .unwrap_or(lines.len());
+    let end = lines[run..]
+        .iter()
+        .position(|l| l.text.trim_end() == "}")
+        .map_or(lines.len(), |i| run + i);
+
+    let (banner, blocks) = parse_body(lines.get(run + 1..end).unwrap_or(&[]));
+    Outline {
+        section,
+        banner,
+        blocks,
+        items: parse_items(
+            section.id,
+            section.source,
+            lines.get(end + 1..).unwrap_or(&[]),
+        ),
+    }
+}
+
+/// Every helper item a sheet's sections can use: the [shared](shared_items)
+/// ones, then the sheet's own in registry order.
+pub fn sheet_items(sheet: Sheet) -> Vec<Item> {
+    let mut items = shared_items();
+    items.extend(registry::sheet(sheet).flat_map(|s| outline(s).items));
+    items
+}
+
+/// The items of [`crate::shared`], which both sheets import. Their
+/// [`Item::section`] is [`registry::SHARED`] and their spans point into
+/// [`registry::SHARED_SOURCE`].
+pub fn shared_items() -> Vec<Item> {
+    let lines: Vec<RawLine> = raw_lines(registry::SHARED_SOURCE)
+        .skip_while(|l| l.text.starts_with("//!") || l.text.is_empty())
+        .collect();
+    parse_items(registry::SHARED, registry::SHARED_SOURCE, &lines)
+}
+
+/// Items defined in more than one section (same [`label`](Item::label))
+/// whose definitions differ, grouped by label. Leading comments don't count;
+/// attributes such as `#[derive(Debug)]` do. Several blocks with one label in
+/// a single section (`impl<T: Num> Point<T>` and `impl<T: Float> Point<T>`)
+/// are not copies.
+pub fn diverging_duplicates(items: &[Item]) -> Vec<Vec<&Item>> {
+    let body = |item: &Item| -> Vec<&str> {
+        item.source
+            .lines()
+            .map(str::trim)
+            .skip_while(|l| l.starts_with("//"))
+            .filter(|l| !l.is_empty())
+            .collect()
+    };
+    let mut by_label: Vec<(String, Vec<&Item>)> = Vec::new();
+    for item in items {
+        let label = item.label();
+        match by_label.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, group)) => group.push(item),
+            None => by_label.push((label, vec![item])),
+        }
+    }
+    by_label
+        .into_iter()
+        .map(|(_, group)| group)
+        .filter(|group| group.iter().any(|i| i.section != group[0].section))
+        .filter(|group| group.iter().any(|i| body(i) != body(group[0])))
+        .collect()
+}
+
+/// An identifier in a line of code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ident {
+    pub text: &'static str,
+    /// Byte offset within the code passed to [`identifiers`].
+    pub offset: usize,
+    /// `true` for `.name(` method calls.
+    pub method: bool,
+}
+
+/// Identifiers in `code`, skipping string/char literals, numbers and comments.
+pub fn identifiers(code: &'static str) -> Vec<Ident> {
+    let bytes = code.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'"' {
+            i = skip_string(bytes, i);
+        } else if b == b'\'' {
+            // 'x' and '\n' are chars; 'a on its own is a lifetime.
+            if bytes.get(i + 1) == Some(&b'\\') || bytes.get(i + 2) == Some(&b'\'') {
+                i = skip_char(bytes, i);
+            } else {
+                i += 1;
+                while i < bytes.len() && is_ident(bytes[i]) {
+                    i += 1;
+                }
+            }
+        } else if b == b'/' && bytes.get(i + 1) == Some(&b'/') {
+            break;
+        } else if b.is_ascii_digit() {
+            // 1_000u64, 2.5 -- but not the `.max` of `7i32.max(..)`.
+            while i < bytes.len()
+                && (is_ident(bytes[i])
+                    || bytes[i] == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+            {
+                i += 1;
+            }
+        } else if is_ident(b) {
+            let start = i;
+            while i < bytes.len() && is_ident(bytes[i]) {
+                i += 1;
+            }
+            let method = start > 0 && bytes[start - 1] == b'.' && bytes.get(i) == Some(&b'(');
+            out.push(Ident {
+                text: &code[start..i],
+                offset: start,
+                method,
+            });
+        } else {
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Splits a line of code from its trailing `//` comment.
+pub fn split_comment(line: &'static str) -> (&'static str, Option<&'static str>) {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => i = skip_string(bytes, i),
+            b'\'' if bytes.get(i + 1) == Some(&b'\\') || bytes.get(i + 2) == Some(&b'\'') => {
+                i = skip_char(bytes, i)
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                return (line[..i].trim_end(), Some(line[i + 2..].trim()));
+            }
+            _ => i += 1,
+        }
+    }
+    (line.trim_end(), None)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RawLine {
+    number: usize,
+    start: usize,
+    text: &'static str,
+}
+
+impl RawLine {
+    fn span(&self) -> Span {
+        Span {
+            start: self.start,
+            end: self.start + self.text.len(),
+            line: self.number,
+            end_line: self.number,
+        }
+    }
+}
+
+fn raw_lines(source: &'static str) -> impl Iterator<Item = RawLine> {
+    let mut start = 0;
+    source
+        .split_inclusive('\n')
+        .enumerate()
+        .map(move |(i, raw)| {
+            let line = RawLine {
+                number: i + 1,
+                start,
+                text: raw.trim_end_matches(['\n', '\r']),
+            };
+            start += raw.len();
+            line
+        })
+}
+
+fn parse_body(lines: &[RawLine]) -> (&'static str, Vec<Block>) {
+    let mut banner = "";
+    let mut in_banner = false;
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+
+    for raw in lines {
+        let trimmed = raw.text.trim();
+        if trimmed.is_empty() {
+            blocks.extend(current.take());
+            continue;
+        }
+        if trimmed.starts_with("// ===") {
+            blocks.extend(current.take());
+            in_banner = !in_banner;
+            continue;
+        }
+        if in_banner {
+            banner = comment_text(trimmed);
+            continue;
+        }
+        if let Some(out) = trimmed.strip_prefix(crate::annotations::MARKER) {
+            if let Some(Block::Code(lines)) = &mut current {
+                if let Some(Line {
+                    kind: LineKind::Code { output, .. },
+                    ..
+                }) = lines.last_mut()
+                {
+                    output.push(out.strip_prefix(' ').unwrap_or(out));
+                }
+            }
+            continue;
+        }
+
+        let (kind, is_code) = if trimmed.starts_with("//") {
+            match mistake(trimmed) {
+                Some(kind) => (kind, true),
+                None => (LineKind::Comment(comment_text(trimmed)), false),
+            }
+        } else {
+            let (code, comment) = split_comment(trimmed);
+            let (comment, output) = match comment {
+                Some(c) if c.starts_with("=>") => (None, vec![c[2..].trim_start()]),
+                other => (other, Vec::new()),
+            };
+            let kind = LineKind::Code {
+                code,
+                comment,
+                output,
+            };
+            (kind, true)
+        };
+        let line = Line {
+            span: raw.span(),
+            kind,
+        };
+        match (&mut current, is_code) {
+            (Some(Block::Code(lines)), true) | (Some(Block::Text(lines)), false) => {
+                lines.push(line)
+            }
+            _ => {
+                blocks.extend(current.take());
+                current = Some(if is_code {
+                    Block::Code(vec![line])
+                } else {
+                    Block::Text(vec![line])
+                });
+            }
+        }
+    }
+    blocks.extend(current);
+    (banner, blocks)
+}
+
+/// `// takes_ownership(s); // would move `s`: error[E0382] ...`
+fn mistake(trimmed: &'static str) -> Option<LineKind> {
+    let (code, note) = trimmed.strip_prefix("// ")?.split_once("//")?;
+    let note = note.trim();
+    crate::compile_fail::error_code(note)?;
+    Some(LineKind::Mistake {
+        code: code.trim(),
+        note,
+    })
+}
+
+fn comment_text(trimmed: &'static str) -> &'static str {
+    let text = trimmed.trim_start_matches('/');
+    text.strip_prefix(' ').unwrap_or(text).trim_end()
+}
+
+fn parse_items(id: &'static str, source: &'static str, lines: &[RawLine]) -> Vec<Item> {
+    let mut items = Vec::new();
+    let mut lead: Option<usize> = None;
+    let mut i = 0;
+    while i < lines.len() {
+        let text = lines[i].text;
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.starts_with("// ---") {
+            lead = None;
+            i += 1;
+            continue;
+        }
+        if trimmed == "#[cfg(test)]" {
+            break;
+        }
+        if trimmed.starts_with("//") || trimmed.starts_with("#[") {
+            lead.get_or_insert(i);
+            i += 1;
+            continue;
+        }
+        let Some((kind, name, trait_name, self_ty)) = item_header(text) else {
+            lead = None;
+            i += 1;
+            continue;
+        };
+
+        let first = lead.take().unwrap_or(i);
+        let last = if trimmed.ends_with(';') || trimmed.ends_with('}') {
+            i
+        } else {
+            lines[i..]
+                .iter()
+                .position(|l| l.text.trim_end() == "}")
+                .map_or(lines.len() - 1, |n| i + n)
+        };
+        let methods = lines[i + 1..=last.max(i)]
+            .iter()
+            .filter_map(|l| {
+                let t = l.text.trim_start();
+                let rest = t
+                    .strip_prefix("pub fn ")
+                    .or_else(|| t.strip_prefix("fn "))?;
+                Some(leading_ident(rest))
+            })
+            .collect();
+        let span = Span {
+            start: lines[first].start,
+            end: lines[last].start + lines[last].text.len(),
+            line: lines[first].number,
+            end_line: lines[last].number,
+        };
+        items.push(Item {
+            kind,
+            name,
+            trait_name,
+            self_ty,
+            methods,
+            section: id,
+            span,
+            source: span.text(source),
+        });
+        i = last + 1;
+    }
+    items
+}
+
+/// Kind, name, implemented trait and written self type, as on [`Item`].
+type Header = (
+    ItemKind,
+    &'static str,
+    Option<&'static str>,
+    Option<&'static str>,
+);
+
+fn item_header(text: &'static str) -> Option<Header> {
+    let rest = text.strip_prefix("pub ").unwrap_or(text);
+    let (kind, rest) = [
+        ("fn ", ItemKind::Fn),
+        ("struct ", ItemKind::Struct),
+        ("enum ", ItemKind::Enum),
+        ("trait ", ItemKind::Trait),
+        ("impl", ItemKind::Impl),
+        ("macro_rules! ", ItemKind::Macro),
+    ]
+    .into_iter()
+    .find_map(|(kw, kind)| Some((kind, rest.strip_prefix(kw)?)))?;
+
+    if kind != ItemKind::Impl {
+        return Some((kind, leading_ident(rest), None, None));
+    }
+    // impl<T> Trait for Type<T> {
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix('<') {
+        Some(generic) => &generic[generic.find('>')? + 1..],
+        None => rest,
+    };
+    let header = rest.split('{').next()?.trim();
+    let (trait_name, ty) = match header.split_once(" for ") {
+        Some((t, ty)) => (Some(t.trim()), ty.trim()),
+        None => (None, header),
+    };
+    let (name, self_ty) = self_type(ty);
+    Some((kind, name, trait_name, self_ty))
+}
+
+/// `User<T>` -> `User`; `dyn fmt::Display + Send` and `&mut dyn Speak` ->
+/// `Display` and `Speak`, keeping the written type.
+fn self_type(ty: &'static str) -> (&'static str, Option<&'static str>) {
+    let mut inner = ty;
+    if let Some(r) = inner.strip_prefix('&') {
+        let r = r.trim_start();
+        // `&'a T`
+        let r = match r.strip_prefix('\'') {
+            Some(l) => l
+                .trim_start_matches(|c: char| is_ident(c as u8))
+                .trim_start(),
+            None => r,
+        };
+        inner = r.strip_prefix("mut ").unwrap_or(r).trim_start();
+    }
+    if let Some(d) = inner.strip_prefix("dyn ") {
+        inner = d.trim_start();
+    }
+    if inner.len() == ty.len() {
+        return (leading_ident(ty), None);
+    }
+    let path = inner.split('+').next().unwrap_or(inner);
+    (trait_ident(path), Some(ty))
+}
+
+/// `Display` for `fmt::Display`, `From` for `From<io::Error>`.
+fn trait_ident(t: &'static str) -> &'static str {
+    let path = t.split('<').next().unwrap_or(t);
+    path.rsplit("::").next().unwrap_or(path).trim()
+}
+
+fn leading_ident(s: &'static str) -> &'static str {
+    let end = s.bytes().position(|b| !is_ident(b)).unwrap_or(s.len());
+    &s[..end]
+}
+
+fn is_ident(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn skip_string(bytes: &[u8], mut i: usize) -> usize {
+    i += 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+fn skip_char(bytes: &[u8], mut i: usize) -> usize {
+    i += 1;
+    if bytes.get(i) == Some(&b'\\') {
+        i += 1;
+    }
+    while i < bytes.len() && bytes[i] != b'\'' {
+        i += 1;
+    }
+    i + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find;
+
+    #[test]
+    fn banner_blocks_and_trailing_comments() {
+        let o = outline(find("variables").unwrap());
+        assert_eq!(o.banner, "1) Variables + Types");
+        let Block::Code(lines) = &o.blocks[0] else {
+            panic!("{:?}", o.blocks[0]);
+        };
+        assert_eq!(
+            lines[0].kind,
+            LineKind::Code {
+                code: "let x = 5;",
+                comment: Some("immutable"),
+                output: vec![]
+            }
+        );
+        assert_eq!(
+            lines[0].span.text(o.section.source),
+            "    let x = 5;               // immutable"
+        );
+        assert_eq!(o.blocks.len(), 3);
+    }
+
+    #[test]
+    fn comments_mistakes_and_output() {
+        let o = outline(find("ownership").unwrap());
+        let lines: Vec<_> = o.code_lines().map(|l| &l.kind).collect();
+        assert!(lines.contains(&&LineKind::Mistake {
+            code: "println!(\"{:?}\", v1);",
+            note: "error[E0382]: use of moved value"
+        }));
+        assert!(o.blocks.iter().any(
+            |b| matches!(b, Block::Text(l) if l[0].kind == LineKind::Comment("Copy vs Move"))
+        ));
+
+        let o = outline(find("control_flow").unwrap());
+        let outputs: Vec<_> = o
+            .code_lines()
+            .filter_map(|l| match &l.kind {
+                LineKind::Code { output, .. } if output.len() > 1 => Some(output.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            outputs,
+            [
+                vec!["for i=0", "for i=1", "for i=2"],
+                vec!["while i=0", "while i=1"]
+            ]
+        );
+    }
+
+    #[test]
+    fn helper_items_with_their_comments() {
+        let o = outline(find("ownership").unwrap());
+        let names: Vec<_> = o.items.iter().map(|i| i.name).collect();
+        assert_eq!(names, ["borrow_str", "borrow_mut", "takes_ownership"]);
+        assert!(o.items[0]
+            .source
+            .starts_with("// Borrow immutably\n#[allow(clippy::ptr_arg)]"));
+        assert!(o.items[0].source.ends_with("}"));
+
+        let o = outline(find("structs").unwrap());
+        let labels: Vec<_> = o.items.iter().map(Item::label).collect();
+        assert_eq!(labels, ["struct User", "impl User"]);
+        assert_eq!(o.items[1].methods, ["new", "birthday", "greet"]);
+
+        // `Msg` and `Point` are imported, not defined here.
+        let o = outline(find("types/struct_enum").unwrap());
+        let labels: Vec<_> = o.items.iter().map(Item::label).collect();
+        assert_eq!(labels, ["fn describe"]);
+
+        // Trait paths and generic arguments are kept: two `From` impls differ.
+        let o = outline(find("errors").unwrap());
+        let labels: Vec<_> = o.items.iter().map(Item::label).collect();
+        assert_eq!(
+            labels,
+            [
+                "enum AppError",
+                "impl fmt::Display for AppError",
+                "impl Error for AppError",
+                "impl From<ParseIntError> for AppError",
+                "impl From<io::Error> for AppError",
+                "fn positive",
+                "fn sum_positive",
+                "fn report"
+            ]
+        );
+
+        // Macros end at their closing brace; the `use` after each isn't an item.
+        let o = outline(find("macros").unwrap());
+        let labels: Vec<_> = o.items.iter().map(Item::label).collect();
+        assert_eq!(
+            labels,
+            [
+                "macro_rules! hashmap",
+                "macro_rules! max",
+                "macro_rules! rpn"
+            ]
+        );
+        assert!(o.items[0].source.starts_with("macro_rules! hashmap {\n"));
+        assert!(o.items[2]
+            .source
+            .ends_with("        rpn!(@[] $($tokens)+)\n    };\n}"));
+    }
+
+    #[test]
+    fn impls_on_trait_objects_are_named_after_the_trait() {
+        let o = outline(find("types/plugins").unwrap());
+        let item = o.items.iter().find(|i| i.self_ty.is_some()).unwrap();
+        assert_eq!(item.label(), "impl dyn Speak + Send + Sync");
+        assert_eq!(item.name, "Speak");
+        assert_eq!(item.methods, ["downcast_ref"]);
+
+        let header = |text| item_header(text).map(|(_, name, _, ty)| (name, ty));
+        assert_eq!(
+            header("impl<'a> fmt::Debug for &'a mut dyn fmt::Display {"),
+            Some(("Display", Some("&'a mut dyn fmt::Display")))
+        );
+        assert_eq!(header("impl<T> Wrapper<T> {"), Some(("Wrapper", None)));
+    }
+
+    #[test]
+    fn tests_are_not_items() {
+        let o = outline(find("functions").unwrap());
+        assert_eq!(o.items.len(), 1);
+        assert_eq!(o.items[0].source, "pub fn add(a: i32, b: i32) -> i32 {\n    a + b // last expression is return value (no semicolon)\n}");
+    }
+
+    #[test]
+    fn uses_finds_helpers_by_name_and_method() {
+        let items = sheet_items(Sheet::Basics);
+        let used = |id| -> Vec<String> {
+            outline(find(id).unwrap())
+                .uses(&items)
+                .iter()
+                .map(|i| i.label())
+                .collect()
+        };
+        assert_eq!(used("functions"), ["fn add"]);
+        assert_eq!(used("ownership"), ["fn borrow_str", "fn borrow_mut"]);
+        assert_eq!(used("structs"), ["struct User", "impl User"]);
+        assert_eq!(used("enums"), ["enum Msg", "fn handle"]);
+        assert_eq!(
+            used("traits"),
+            ["fn id", "trait Speak", "impl Speak for i32"]
+        );
+        assert_eq!(used("lifetimes"), ["fn pick_longer"]);
+        assert!(used("patterns").is_empty());
+        assert_eq!(
+            used("macros"),
+            [
+                "macro_rules! hashmap",
+                "macro_rules! max",
+                "macro_rules! rpn"
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_items_are_parsed_from_the_shared_module() {
+        let items = shared_items();
+        let labels: Vec<_> = items.iter().map(Item::label).collect();
+        assert_eq!(
+            labels,
+            [
+                "enum Msg",
+                "fn id",
+                "trait Speak",
+                "impl Speak for i32",
+                "impl Speak for String"
+            ]
+        );
+        assert!(items[0]
+            .source
+            .starts_with("#[derive(Debug, Clone, PartialEq, Eq)]\n#[cfg_attr("));
+        for item in &items {
+            assert_eq!(item.section, registry::SHARED);
+            assert_eq!(item.span.text(registry::SHARED_SOURCE), item.source);
+        }
+    }
+
+    #[test]
+    fn no_item_is_defined_twice_with_different_bodies() {
+        let mut items = shared_items();
+        for section in registry::registry() {
+            items.extend(outline(section).items);
+        }
+        let diverging: Vec<Vec<String>> = diverging_duplicates(&items)
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|i| format!("{} in {}", i.label(), i.section))
+                    .collect()
+            })
+            .collect();
+        assert!(
+            diverging.is_empty(),
+            "move these into crate::shared: {diverging:?}"
+        );
+    }
+
+    #[test]
+    fn diverging_copies_are_flagged() {
+        let shared = shared_items();
+        let msg = shared[0].clone();
+        // The same enum without its derive, as the basics sheet had it.
+        let copy = Item {
+            section: "enums",
+            source:
+                "pub enum Msg {\n    Quit,\n    Write(String),\n    Move { x: i32, y: i32 },\n}",
+            ..msg.clone()
+        };
+        let items = [msg.clone(), copy];
+        let groups = diverging_duplicates(&items);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0][1].section, "enums");
+
+        // Only comments differ: not a divergence.
+        let commented = Item {
+            section: "enums",
+            source: "// Enums + match\n#[derive(Debug, Clone, PartialEq, Eq)]\n#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]\n#[cfg_attr(feature = \"derive\", derive(cheat_derive::Speak))]\npub enum Msg {\n    Quit,\n    Write(String),\n    Move { x: i32, y: i32 },\n}",
+            ..msg.clone()
+        };
+        assert!(diverging_duplicates(&[msg.clone(), commented]).is_empty());
+
+        // Two blocks in the same section are not copies of each other.
+        let second_block = Item {
+            source: "pub enum Msg {}",
+            ..msg.clone()
+        };
+        assert!(diverging_duplicates(&[msg, second_block]).is_empty());
+    }
+
+    #[test]
+    fn identifiers_skip_literals() {
+        let ids: Vec<_> = identifiers("let b = 7i32.max(x) + 'a' as i32; // c")
+            .iter()
+            .map(|i| (i.text, i.method))
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                ("let", false),
+                ("b", false),
+                ("max", true),
+                ("x", false),
+                ("as", false),
+                ("i32", false)
+            ]
+        );
+        let ids: Vec<_> = identifiers("println!(\"{x} // y\", z)")
+            .iter()
+            .map(|i| i.text)
+            .collect();
+        assert_eq!(ids, ["println", "z"]);
+    }
+
+    #[test]
+    fn spans_are_byte_accurate() {
+        for section in registry::registry() {
+            let o = outline(section);
+            for line in o.code_lines() {
+                let text = line.span.text(section.source);
+                assert_eq!(section.source.lines().nth(line.span.line - 1), Some(text));
+            }
+            for item in &o.items {
+                assert_eq!(item.span.text(section.source), item.source);
+            }
+        }
+    }
+}
@@ -0,0 +1,418 @@
+//! Spaced-repetition progress for quiz results, one card per section.
+//!
+//! Scheduling follows SM-2: a section answered well comes back after 1 day,
+//! then 6, then the previous interval times its ease factor; a section
+//! answered badly starts over at 1 day and loses ease, so the ones someone
+//! keeps missing come up most often.
+//!
+//! The state is a small versioned text file, so it can be copied between
+//! machines and [merged](Progress::merge):
+//!
+//! ```text
+//! cheat-progress 1
+//! # section  reps  interval  ease  due    right  wrong  last
+//! lifetimes  0     1         196   20380  1      3      20379
+//! ```
+//!
+//! Fields are tab-separated. Dates are days since the Unix epoch and `ease`
+//! is in hundredths (`250` is SM-2's starting 2.5), which keeps the file exact.
+//! Intervals stop growing at [`MAX_INTERVAL`] and ease at [`MAX_EASE`], so a
+//! long-lived (or hand-edited) file can't overflow the arithmetic.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The format version written in the header line.
+pub const VERSION: u32 = 1;
+
+/// The lowest ease, SM-2's 1.3.
+pub const MIN_EASE: u32 = 130;
+/// The highest ease: 10.0, reached after 75 perfect reviews in a row.
+pub const MAX_EASE: u32 = 1000;
+/// The longest interval, about 100 years.
+pub const MAX_INTERVAL: u32 = 36_500;
+
+const HEADER: &str = "cheat-progress";
+const COLUMNS: &str = "# section\treps\tinterval\tease\tdue\tright\twrong\tlast";
+
+/// Review state of one section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    /// Successful reviews in a row.
+    pub repetitions: u32,
+    /// Days until the next review.
+    pub interval: u32,
+    /// Ease factor, in hundredths; from [`MIN_EASE`] to [`MAX_EASE`].
+    pub ease: u32,
+    /// Day the section is due again.
+    pub due: u64,
+    /// Answers graded right and wrong, over all reviews.
+    pub right: u32,
+    pub wrong: u32,
+    /// Day of the last review.
+    pub last: u64,
+}
+
+impl Default for Card {
+    fn default() -> Self {
+        Card {
+            repetitions: 0,
+            interval: 0,
+            ease: 250,
+            due: 0,
+            right: 0,
+            wrong: 0,
+            last: 0,
+        }
+    }
+}
+
+impl Card {
+    /// Applies one SM-2 review of `quality` (0-5, 3 and up is a pass).
+    pub fn review(&mut self, quality: u8, today: u64) {
+        let q = u32::from(quality.min(5));
+        if q >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => {
+                    let next = (u64::from(self.interval) * u64::from(self.ease) + 50) / 100;
+                    next.min(u64::from(MAX_INTERVAL)) as u32
+                }
+            };
+            self.repetitions = self.repetitions.saturating_add(1);
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+        // EF' = EF + 0.1 - (5 - q) * (0.08 + (5 - q) * 0.02), in hundredths.
+        let miss = 5 - q;
+        let change = 10 - i64::from(miss * (8 + miss * 2));
+        self.ease = (i64::from(self.ease) + change).clamp(MIN_EASE.into(), MAX_EASE.into()) as u32;
+        self.last = today;
+        self.due = today.saturating_add(self.interval.into());
+    }
+
+    /// `true` when the section should be reviewed on `today`.
+    pub fn is_due(&self, today: u64) -> bool {
+        self.due <= today
+    }
+}
+
+/// SM-2 quality for a section's answers in one quiz: 4 for all right, 3 for
+/// at least half, 1 otherwise.
+pub fn quality(right: u32, total: u32) -> u8 {
+    if right >= total {
+        4
+    } else if u64::from(right) * 2 >= u64::from(total) {
+        3
+    } else {
+        1
+    }
+}
+
+/// Today, as days since the Unix epoch.
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400)
+}
+
+/// Everyone's cards, keyed by section ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub cards: BTreeMap<String, Card>,
+}
+
+/// A malformed progress file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the file.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Progress {
+    /// Records one quiz's answers for `section` as a single review.
+    pub fn record(&mut self, section: &str, right: u32, total: u32, today: u64) {
+        let card = self.cards.entry(section.to_string()).or_default();
+        card.right = card.right.saturating_add(right);
+        card.wrong = card.wrong.saturating_add(total.saturating_sub(right));
+        card.review(quality(right, total), today);
+    }
+
+    /// Cards due on `today`, hardest first: lowest ease, then most overdue.
+    pub fn due(&self, today: u64) -> Vec<(&str, &Card)> {
+        let mut due: Vec<_> = self
+            .cards
+            .iter()
+            .filter(|(_, c)| c.is_due(today))
+            .map(|(id, c)| (id.as_str(), c))
+            .collect();
+        due.sort_by_key(|(id, c)| (c.ease, c.due, *id));
+        due
+    }
+
+    /// Folds in another machine's progress. For a section known to both, the
+    /// card reviewed last wins (the more-reviewed one on a tie), so merging
+    /// the same file twice changes nothing.
+    pub fn merge(&mut self, other: Progress) {
+        for (id, theirs) in other.cards {
+            let reviews = |c: &Card| (c.last, u64::from(c.right) + u64::from(c.wrong));
+            match self.cards.get_mut(&id) {
+                Some(ours) if reviews(ours) >= reviews(&theirs) => {}
+                Some(ours) => *ours = theirs,
+                None => {
+                    self.cards.insert(id, theirs);
+                }
+            }
+        }
+    }
+
+    /// Parses the text format written by [`Display`](fmt::Display).
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let error = |line, message: String| ParseError { line, message };
+        let mut lines = text.lines().enumerate();
+        let version = lines
+            .next()
+            .and_then(|(_, l)| l.strip_prefix(HEADER))
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .ok_or_else(|| error(1, format!("expected a `{HEADER} <version>` header")))?;
+        if version != VERSION {
+            return Err(error(
+                1,
+                format!("version {version} is not supported (expected {VERSION})"),
+            ));
+        }
+
+        let mut progress = Progress::default();
+        for (i, line) in lines {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [id, reps, interval, ease, due, right, wrong, last] = fields[..] else {
+                return Err(error(
+                    i + 1,
+                    format!("expected 8 fields, got {}", fields.len()),
+                ));
+            };
+            let num = |field: &str| {
+                field
+                    .parse::<u64>()
+                    .map_err(|_| error(i + 1, format!("`{field}` is not a number")))
+            };
+            let small = |field: &str| {
+                u32::try_from(num(field)?)
+                    .map_err(|_| error(i + 1, format!("`{field}` is too large")))
+            };
+            let in_range = |field: &str, name, range: std::ops::RangeInclusive<u32>| {
+                let value = small(field)?;
+                if !range.contains(&value) {
+                    return Err(error(
+                        i + 1,
+                        format!(
+                            "{name} `{field}` is out of range ({}-{})",
+                            range.start(),
+                            range.end()
+                        ),
+                    ));
+                }
+                Ok(value)
+            };
+            progress.cards.insert(
+                id.to_string(),
+                Card {
+                    repetitions: small(reps)?,
+                    interval: in_range(interval, "interval", 0..=MAX_INTERVAL)?,
+                    ease: in_range(ease, "ease", MIN_EASE..=MAX_EASE)?,
+                    due: num(due)?,
+                    right: small(right)?,
+                    wrong: small(wrong)?,
+                    last: num(last)?,
+                },
+            );
+        }
+        Ok(progress)
+    }
+
+    /// Reads `path`; a missing file is empty progress.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes to `path` (through a temporary file, so a crash can't leave
+    /// half a file), creating its directory.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, self.to_string())?;
+        std::fs::rename(tmp, path)
+    }
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER} {VERSION}")?;
+        writeln!(f, "{COLUMNS}")?;
+        for (id, c) in &self.cards {
+            writeln!(
+                f,
+                "{id}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                c.repetitions, c.interval, c.ease, c.due, c.right, c.wrong, c.last
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sm2_intervals_grow_and_reset() {
+        let mut card = Card::default();
+        card.review(4, 100);
+        assert_eq!((card.interval, card.due, card.ease), (1, 101, 250));
+        card.review(4, 101);
+        assert_eq!((card.interval, card.due), (6, 107));
+        card.review(5, 107);
+        assert_eq!((card.interval, card.ease), (15, 260));
+        card.review(3, 122);
+        assert_eq!((card.interval, card.ease), (39, 246));
+        card.review(1, 161);
+        assert_eq!((card.repetitions, card.interval, card.ease), (0, 1, 192));
+        for day in 0..10 {
+            card.review(0, 162 + day);
+        }
+        assert_eq!(card.ease, 130);
+    }
+
+    #[test]
+    fn quality_from_a_quiz_score() {
+        assert_eq!(quality(3, 3), 4);
+        assert_eq!(quality(1, 2), 3);
+        assert_eq!(quality(1, 3), 1);
+        assert_eq!(quality(0, 1), 1);
+    }
+
+    #[test]
+    fn missed_sections_come_back_first() {
+        let mut p = Progress::default();
+        p.record("lifetimes", 0, 2, 10);
+        p.record("result", 1, 3, 10);
+        p.record("vec", 2, 2, 10);
+        assert!(p.due(10).is_empty());
+        let due: Vec<_> = p.due(11).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(due, ["lifetimes", "result", "vec"]);
+        assert_eq!(p.cards["lifetimes"].wrong, 2);
+    }
+
+    #[test]
+    fn text_format_round_trips() {
+        let mut p = Progress::default();
+        p.record("types/references", 1, 2, 20_000);
+        p.record("ownership", 2, 2, 20_001);
+        let text = p.to_string();
+        assert!(text.starts_with("cheat-progress 1\n# section\t"));
+        assert!(text.contains("\nownership\t1\t1\t250\t20002\t2\t0\t20001\n"));
+        assert_eq!(Progress::parse(&text), Ok(p));
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        let err = Progress::parse("cheat-progress 2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: version 2 is not supported (expected 1)"
+        );
+        assert_eq!(Progress::parse("").unwrap_err().line, 1);
+        let err = Progress::parse("cheat-progress 1\n\nvec\t1\t2\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: expected 8 fields, got 3");
+        let err = Progress::parse("cheat-progress 1\nvec\t1\t1\tx\t0\t0\t0\t0\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: `x` is not a number");
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        let err =
+            Progress::parse("cheat-progress 1\nvec\t1\t36501\t250\t0\t0\t0\t0\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: interval `36501` is out of range (0-36500)"
+        );
+        let err = Progress::parse("cheat-progress 1\nvec\t1\t1\t99\t0\t0\t0\t0\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: ease `99` is out of range (130-1000)"
+        );
+        let err =
+            Progress::parse("cheat-progress 1\nvec\t1\t1\t4294967296\t0\t0\t0\t0\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: `4294967296` is too large");
+    }
+
+    #[test]
+    fn huge_values_saturate_instead_of_overflowing() {
+        let max = u32::MAX;
+        let text = format!(
+            "cheat-progress 1\nvec\t{max}\t{MAX_INTERVAL}\t{MAX_EASE}\t{}\t{max}\t{max}\t0\n",
+            u64::MAX
+        );
+        let mut p = Progress::parse(&text).unwrap();
+        p.record("vec", max, max, u64::MAX - 1);
+        let card = p.cards["vec"];
+        assert_eq!(
+            (card.repetitions, card.interval, card.ease),
+            (max, MAX_INTERVAL, MAX_EASE)
+        );
+        assert_eq!((card.right, card.wrong, card.due), (max, max, u64::MAX));
+        p.record("vec", 0, max, 5);
+        assert_eq!(p.cards["vec"].interval, 1);
+        assert_eq!(quality(max, max), 4);
+        assert_eq!(quality(max / 2 + 1, max), 3);
+    }
+
+    #[test]
+    fn merge_keeps_the_latest_review() {
+        let mut laptop = Progress::default();
+        laptop.record("lifetimes", 0, 1, 10);
+        laptop.record("vec", 1, 1, 10);
+        let mut desktop = Progress::default();
+        desktop.record("lifetimes", 1, 1, 12);
+        desktop.record("result", 0, 1, 11);
+
+        let mut merged = laptop.clone();
+        merged.merge(desktop.clone());
+        assert_eq!(merged.cards["lifetimes"], desktop.cards["lifetimes"]);
+        assert_eq!(merged.cards["vec"], laptop.cards["vec"]);
+        assert_eq!(merged.cards.len(), 3);
+
+        let mut again = merged.clone();
+        again.merge(desktop.clone());
+        again.merge(laptop);
+        assert_eq!(again, merged);
+    }
+}
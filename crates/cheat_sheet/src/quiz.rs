@@ -0,0 +1,333 @@
+//! Quiz questions generated from the sheets themselves.
+//!
+//! There is no question bank: every `//=>` annotation becomes a "what does
+//! this print?" question, and every commented-out error line (see
+//! [`compile_fail`](crate::compile_fail)) becomes a pair of "does this
+//! compile?" questions, one with the line put back and one without it.
+//! Compile questions are graded by running the local `rustc`, not by trusting
+//! the comment.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+
+use crate::annotations::MARKER;
+use crate::compile_fail::{self, compile, Case};
+use crate::outline::{self, Block, LineKind, Outline};
+use crate::registry::Section;
+use crate::snapshot;
+
+/// One question about a demo.
+#[derive(Debug, Clone)]
+pub struct Question {
+    pub section: &'static Section,
+    /// 1-based line within `section.source` the question is about.
+    pub line: usize,
+    /// The demo code shown, up to the end of the block the line is in. The
+    /// line itself is marked with `//=> ?` or `// <- ?`.
+    pub code: String,
+    pub kind: Kind,
+}
+
+#[derive(Debug, Clone)]
+pub enum Kind {
+    /// "What does the marked line print?", with the annotated answer.
+    Prints(&'static str),
+    /// "Does this compile?": the program rustc grades it with, and the error
+    /// code the sheet claims when it should not compile.
+    Compiles {
+        program: String,
+        claimed: Option<&'static str>,
+    },
+}
+
+/// The outcome of one answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grade {
+    pub correct: bool,
+    /// The right answer, e.g. `4 or 5` or `no: rustc reports E0382`.
+    pub answer: String,
+}
+
+impl Question {
+    pub fn prompt(&self) -> &'static str {
+        match self.kind {
+            Kind::Prints(_) => "What does the marked line print?",
+            Kind::Compiles { .. } => "Does this compile? (y/n)",
+        }
+    }
+
+    /// Grades `reply`. Compile questions run `rustc`, hence the `io::Result`.
+    pub fn grade(&self, reply: &str) -> io::Result<Grade> {
+        let reply = reply.trim();
+        match &self.kind {
+            Kind::Prints(expected) => {
+                let unordered = self.section.unordered_output();
+                Ok(Grade {
+                    correct: snapshot::normalize(reply, unordered)
+                        == snapshot::normalize(expected, unordered),
+                    answer: expected.to_string(),
+                })
+            }
+            Kind::Compiles { program, .. } => {
+                let compiled = compile(program)?;
+                let said_yes = match reply.to_lowercase().as_str() {
+                    "y" | "yes" => Some(true),
+                    "n" | "no" => Some(false),
+                    _ => None,
+                };
+                let answer = match compiled.codes.first() {
+                    _ if compiled.ok => "yes".to_string(),
+                    Some(code) => format!("no: rustc reports {code}"),
+                    None => "no".to_string(),
+                };
+                Ok(Grade {
+                    correct: said_yes == Some(compiled.ok),
+                    answer,
+                })
+            }
+        }
+    }
+}
+
+/// Every question about `section`, in source order.
+pub fn questions_in(section: &'static Section) -> Vec<Question> {
+    let outline = outline::outline(section);
+    let mut out: Vec<Question> = outline
+        .code_lines()
+        .filter_map(|line| match &line.kind {
+            LineKind::Code { output, .. } if output.len() == 1 => Some(Question {
+                section,
+                line: line.span.line,
+                code: context(&outline, line.span.line, Mark::Prints),
+                kind: Kind::Prints(output[0]),
+            }),
+            _ => None,
+        })
+        .collect();
+    for case in compile_fail::cases_in(section) {
+        out.push(Question {
+            section,
+            line: case.line,
+            code: context(&outline, case.line, Mark::Uncomment(case)),
+            kind: Kind::Compiles {
+                program: case.program(),
+                claimed: Some(case.expected),
+            },
+        });
+        out.push(Question {
+            section,
+            line: case.line,
+            code: context(&outline, case.line, Mark::Skip),
+            kind: Kind::Compiles {
+                program: compile_fail::standalone(section),
+                claimed: None,
+            },
+        });
+    }
+    out.sort_by_key(|q| q.line);
+    out
+}
+
+/// Every question about `sections`, section by section.
+pub fn questions(sections: impl IntoIterator<Item = &'static Section>) -> Vec<Question> {
+    sections.into_iter().flat_map(questions_in).collect()
+}
+
+enum Mark {
+    /// Replace the line's `//=>` annotation with `//=> ?`.
+    Prints,
+    /// Put the commented-out line back, marked `// <- ?`.
+    Uncomment(Case),
+    /// Leave the commented-out line out entirely.
+    Skip,
+}
+
+/// The demo from the top of `run()` to the end of the block holding `line`.
+/// Annotations are stripped (they are the answers) and so are the other
+/// commented-out error lines (their notes give compile answers away).
+fn context(outline: &Outline, line: usize, mark: Mark) -> String {
+    let source = outline.section.source;
+    let mut out = String::new();
+    let mut last = None;
+    for block in &outline.blocks {
+        let (Block::Text(lines) | Block::Code(lines)) = block;
+        // Keep the blank lines between blocks.
+        if last.is_some_and(|n| lines[0].span.line > n + 1) {
+            out.push('\n');
+        }
+        last = lines.last().map(|l| l.span.line);
+        for l in lines {
+            let text = l.span.text(source);
+            let text = text.strip_prefix("    ").unwrap_or(text);
+            let target = l.span.line == line;
+            let text = match (&l.kind, &mark) {
+                (LineKind::Mistake { .. }, Mark::Uncomment(case)) if target => {
+                    let indent = &text[..text.len() - text.trim_start().len()];
+                    format!("{indent}{} // <- ?", case.code)
+                }
+                (LineKind::Mistake { .. }, _) => continue,
+                (LineKind::Code { .. }, _) => {
+                    let code = text.split_once(MARKER).map_or(text, |(c, _)| c).trim_end();
+                    match mark {
+                        Mark::Prints if target => format!("{code} //=> ?"),
+                        _ => code.to_string(),
+                    }
+                }
+                (LineKind::Comment(_), _) => text.to_string(),
+            };
+            out.push_str(&text);
+            out.push('\n');
+        }
+        if lines.iter().any(|l| l.span.line == line) {
+            break;
+        }
+    }
+    out
+}
+
+/// Right/total counts, overall, per section and per section tag.
+#[derive(Debug, Clone, Default)]
+pub struct Tally {
+    pub right: usize,
+    pub total: usize,
+    pub by_section: BTreeMap<&'static str, (usize, usize)>,
+    pub by_tag: BTreeMap<&'static str, (usize, usize)>,
+}
+
+impl Tally {
+    /// Counts one graded answer towards the totals and each of the section's
+    /// tags.
+    pub fn record(&mut self, section: &Section, correct: bool) {
+        let count = |(right, total): &mut (usize, usize)| {
+            *total += 1;
+            *right += usize::from(correct);
+        };
+        self.total += 1;
+        self.right += usize::from(correct);
+        count(self.by_section.entry(section.id).or_default());
+        for tag in section.tags {
+            count(self.by_tag.entry(tag).or_default());
+        }
+    }
+}
+
+impl fmt::Display for Tally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.by_tag.keys().map(|t| t.len()).max().unwrap_or(0);
+        for (tag, (right, total)) in &self.by_tag {
+            writeln!(f, "{tag:<width$}  {right}/{total}")?;
+        }
+        write!(f, "{:<width$}  {}/{}", "total", self.right, self.total)
+    }
+}
+
+/// Shuffles `items` deterministically for a given `seed` (xorshift64*, so
+/// `cheat quiz --seed N` replays the same quiz).
+pub fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed | 1;
+    let mut next = move || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    };
+    for i in (1..items.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{self, find};
+
+    #[test]
+    fn print_questions_show_the_demo_up_to_the_line() {
+        let qs = questions_in(find("vec").unwrap());
+        let Kind::Prints(answer) = qs[0].kind else {
+            panic!("{:?}", qs[0]);
+        };
+        assert_eq!(answer, "nums=[1, 2, 3, 4], first=1, maybe_first=Some(1)");
+        assert!(qs[0].code.contains("let first = nums[0];\n"));
+        assert!(qs[0]
+            .code
+            .ends_with("maybe_first={maybe_first:?}\", nums); //=> ?\n"));
+
+        let qs = questions_in(find("patterns").unwrap());
+        let q = qs.last().unwrap();
+        assert!(q
+            .code
+            .contains("match 5 {\n    1..=3 => println!(\"1..=3\"),\n"));
+        assert!(q
+            .code
+            .contains("    4 | 5 => println!(\"4 or 5\"), //=> ?\n"));
+        assert!(q.code.ends_with("    _ => println!(\"other\"),\n}\n"));
+        assert!(!q.code.contains("//=> if let"));
+    }
+
+    #[test]
+    fn every_error_line_asks_both_ways() {
+        let qs = questions(registry::registry());
+        let compiles = qs
+            .iter()
+            .filter(|q| matches!(q.kind, Kind::Compiles { .. }))
+            .count();
+        assert_eq!(compiles, 2 * compile_fail::cases().len());
+
+        let qs = questions_in(find("ownership").unwrap());
+        let broken = qs
+            .iter()
+            .find(|q| {
+                matches!(
+                    q.kind,
+                    Kind::Compiles {
+                        claimed: Some(_),
+                        ..
+                    }
+                ) && q.line == 23
+            })
+            .unwrap();
+        assert!(broken.code.contains("\nprintln!(\"{:?}\", v1); // <- ?\n"));
+        assert!(!broken.code.contains("takes_ownership(s);"));
+        assert!(!broken.code.contains("error["));
+    }
+
+    #[test]
+    fn print_answers_are_trimmed_and_exact() {
+        let q = &questions_in(find("patterns").unwrap())[0];
+        assert!(q.grade("  tuple destructure: p=1, q=2 \n").unwrap().correct);
+        assert!(!q.grade("tuple destructure: p=2, q=1").unwrap().correct);
+        let hashmap = &questions_in(find("hashmap").unwrap())[0];
+        let Kind::Prints(answer) = hashmap.kind else {
+            panic!()
+        };
+        assert!(hashmap.grade(answer).unwrap().correct);
+    }
+
+    #[test]
+    fn tally_counts_per_tag() {
+        let mut tally = Tally::default();
+        tally.record(find("ownership").unwrap(), true);
+        tally.record(find("result").unwrap(), false);
+        tally.record(find("ownership").unwrap(), false);
+        assert_eq!(tally.by_tag["OWNERSHIP"], (1, 2));
+        assert_eq!(tally.by_tag["RESULT"], (0, 1));
+        assert_eq!(tally.by_section["ownership"], (1, 2));
+        assert_eq!((tally.right, tally.total), (1, 3));
+        assert!(tally.to_string().ends_with("total      1/3"));
+    }
+
+    #[test]
+    fn shuffle_is_seeded() {
+        let mut a: Vec<u32> = (0..20).collect();
+        let mut b = a.clone();
+        shuffle(&mut a, 7);
+        shuffle(&mut b, 7);
+        assert_eq!(a, b);
+        assert_ne!(a, (0..20).collect::<Vec<_>>());
+        a.sort();
+        assert_eq!(a, (0..20).collect::<Vec<_>>());
+    }
+}
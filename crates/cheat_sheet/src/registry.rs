@@ -0,0 +1,284 @@
+//! Section registry: every section of both sheets, with stable IDs and tags.
+//!
+//! IDs are the module names. Sections of the [`types`](crate::types) sheet are
+//! prefixed with `types/` so they don't collide with the basics sheet
+//! (`vec` vs `types/vec`).
+
+use std::fmt;
+
+/// Which cheat sheet a section belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sheet {
+    /// The numbered walk-through (`cheat_sheet::basics`).
+    Basics,
+    /// The types tour (`cheat_sheet::types`).
+    Types,
+}
+
+impl Sheet {
+    pub fn name(self) -> &'static str {
+        match self {
+            Sheet::Basics => "basics",
+            Sheet::Types => "types",
+        }
+    }
+
+    /// Human-readable title, used as a heading by exporters.
+    pub fn title(self) -> &'static str {
+        match self {
+            Sheet::Basics => "Rust cheat sheet",
+            Sheet::Types => "Rust types cheat sheet",
+        }
+    }
+}
+
+impl fmt::Display for Sheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// One section of a sheet.
+#[derive(Debug, Clone, Copy)]
+pub struct Section {
+    /// Stable ID, e.g. `ownership` or `types/vec`.
+    pub id: &'static str,
+    pub sheet: Sheet,
+    /// 1-based position within its sheet.
+    pub number: u32,
+    pub title: &'static str,
+    /// Search tags, upper case (OWNERSHIP, STRINGS, VEC, ...).
+    pub tags: &'static [&'static str],
+    /// Path of the section's module, relative to the workspace root.
+    pub file: &'static str,
+    /// Full text of the section's module.
+    pub source: &'static str,
+    /// Path of the checked-in stdout snapshot, relative to the workspace root.
+    pub output_file: &'static str,
+    /// Checked-in stdout of `run()` (see [`crate::snapshot`]).
+    pub output: &'static str,
+    /// The section's `run()` entry point.
+    pub run: fn(),
+    /// Cargo features the section is compiled with (and only with). Such
+    /// sections use crates from crates.io, so they can't be built with bare
+    /// `rustc` by [`compile_fail`](crate::compile_fail) or `cheat try`.
+    pub features: &'static [&'static str],
+}
+
+impl Section {
+    /// Case-insensitive tag check.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// The module name: `vec` for both `vec` and `types/vec`.
+    pub fn module(&self) -> &'static str {
+        self.id.rsplit('/').next().unwrap_or(self.id)
+    }
+
+    /// Whether the section needs crates from crates.io (see `features`).
+    pub fn needs_cargo(&self) -> bool {
+        !self.features.is_empty()
+    }
+
+    /// Whether the output prints hash-ordered collections, so snapshot
+    /// comparisons must ignore entry order.
+    pub fn unordered_output(&self) -> bool {
+        self.has_tag("HASHMAP") || self.has_tag("HASHSET")
+    }
+
+    /// Source lines containing `term` (case-insensitive), with 1-based line numbers.
+    pub fn matching_lines<'a>(
+        &self,
+        term: &'a str,
+    ) -> impl Iterator<Item = (usize, &'static str)> + 'a {
+        let term = term.to_lowercase();
+        self.source
+            .lines()
+            .enumerate()
+            .filter(move |(_, line)| line.to_lowercase().contains(&term))
+            .map(|(i, line)| (i + 1, line))
+    }
+}
+
+macro_rules! section {
+    ($sheet:ident / $module:ident, $id:literal, $number:literal, $title:literal, [$($tag:literal),* $(,)?] $(, features = [$($feature:literal),*])?) => {
+        Section {
+            id: $id,
+            sheet: section!(@sheet $sheet),
+            number: $number,
+            title: $title,
+            tags: &[$($tag),*],
+            file: concat!("crates/cheat_sheet/src/", stringify!($sheet), "/", stringify!($module), ".rs"),
+            source: include_str!(concat!(stringify!($sheet), "/", stringify!($module), ".rs")),
+            output_file: concat!("crates/cheat_sheet/snapshots/", stringify!($sheet), "/", stringify!($module), ".out"),
+            output: include_str!(concat!("../snapshots/", stringify!($sheet), "/", stringify!($module), ".out")),
+            run: crate::$sheet::$module::run,
+            features: &[$($($feature),*)?],
+        }
+    };
+    (@sheet basics) => { Sheet::Basics };
+    (@sheet types) => { Sheet::Types };
+}
+
+#[rustfmt::skip]
+static SECTIONS: &[Section] = &[
+    section!(basics/variables, "variables", 1, "Variables + Types", ["VARIABLES", "CONST"]),
+    section!(basics/functions, "functions", 2, "Functions", ["FUNCTIONS"]),
+    section!(basics/control_flow, "control_flow", 3, "Control Flow", ["CONTROL_FLOW", "LOOPS"]),
+    section!(basics/ownership, "ownership", 4, "Ownership + Borrowing", ["OWNERSHIP", "BORROWING"]),
+    section!(basics/strings, "strings", 5, "Strings", ["STRINGS"]),
+    section!(basics/vec, "vec", 6, "Collections: Vec", ["VEC", "COLLECTIONS"]),
+    section!(basics/hashmap, "hashmap", 7, "Collections: HashMap", ["HASHMAP", "COLLECTIONS"]),
+    section!(basics/structs, "structs", 8, "Structs + impl", ["STRUCTS"]),
+    section!(basics/enums, "enums", 9, "Enums + match", ["ENUMS", "MATCH"]),
+    section!(basics/result, "result", 10, "Option + Result", ["RESULT", "OPTION"]),
+    section!(basics/traits, "traits", 11, "Generics + Traits", ["TRAITS", "GENERICS"]),
+    section!(basics/lifetimes, "lifetimes", 12, "Lifetimes (minimal)", ["LIFETIMES"]),
+    section!(basics/patterns, "patterns", 13, "Pattern tricks", ["PATTERNS", "MATCH"]),
+    section!(basics/commands, "commands", 14, "Worked example: a command interpreter", ["COMMANDS", "ENUMS", "MATCH", "ERRORS"]),
+    section!(basics/errors, "errors", 15, "Custom error types", ["ERRORS", "RESULT"]),
+    section!(basics/macros, "macros", 16, "Declarative macros: macro_rules!", ["MACROS"]),
+    #[cfg(feature = "derive")]
+    section!(basics/derive, "derive", 17, "Derive macros: #[derive(Speak)]", ["DERIVE", "MACROS", "TRAITS"], features = ["derive"]),
+    section!(types/primitives, "types/primitives", 1, "Primitives", ["PRIMITIVES"]),
+    section!(types/strings, "types/strings", 2, "Strings", ["STRINGS"]),
+    section!(types/references, "types/references", 3, "References & Mutability", ["REFERENCES", "BORROWING"]),
+    section!(types/unit, "types/unit", 4, "Unit Type", ["UNIT"]),
+    section!(types/tuples, "types/tuples", 5, "Tuples", ["TUPLES"]),
+    section!(types/arrays, "types/arrays", 6, "Arrays & Slices", ["ARRAYS", "SLICES"]),
+    section!(types/vec, "types/vec", 7, "Vec (growable array)", ["VEC", "COLLECTIONS"]),
+    section!(types/option_result, "types/option_result", 8, "Option / Result", ["OPTION", "RESULT"]),
+    section!(types/string_collections, "types/string_collections", 9, "String/Slice Collections", ["STRINGS", "COLLECTIONS"]),
+    section!(types/hashmap, "types/hashmap", 10, "HashMap / HashSet", ["HASHMAP", "HASHSET", "COLLECTIONS"]),
+    section!(types/struct_enum, "types/struct_enum", 11, "Struct / Enum", ["STRUCTS", "ENUMS"]),
+    section!(types/generics, "types/generics", 12, "Generics", ["GENERICS"]),
+    section!(types/trait_object, "types/trait_object", 13, "Trait Object (dynamic dispatch)", ["TRAITS"]),
+    section!(types/conversions, "types/conversions", 14, "Common Conversions", ["CONVERSIONS"]),
+    section!(types/clones, "types/clones", 15, "Ownership Clones (when needed)", ["OWNERSHIP", "CLONE"]),
+    section!(types/geometry, "types/geometry", 16, "Geometry: operator overloading on Point<T>", ["OPERATORS", "GENERICS", "TRAITS"]),
+    section!(types/plugins, "types/plugins", 17, "Trait objects: a plugin registry", ["TRAITS", "PLUGINS", "ANY"]),
+    #[cfg(feature = "serde")]
+    section!(types/serialization, "types/serialization", 18, "Serde: JSON / TOML round-trips", ["SERDE", "ENUMS", "STRUCTS"], features = ["serde"]),
+];
+
+/// Every section of both sheets: basics first, then types, each in sheet order.
+pub fn registry() -> &'static [Section] {
+    SECTIONS
+}
+
+/// Looks a section up by its ID.
+pub fn find(id: &str) -> Option<&'static Section> {
+    SECTIONS.iter().find(|s| s.id == id)
+}
+
+/// Resolves what a user typed into a section: an exact ID, then a
+/// case-insensitive ID, then the first section carrying that tag.
+///
+/// `vec` resolves to the basics section; use `types/vec` for the other one.
+pub fn resolve(query: &str) -> Option<&'static Section> {
+    find(query)
+        .or_else(|| SECTIONS.iter().find(|s| s.id.eq_ignore_ascii_case(query)))
+        .or_else(|| with_tag(query).next())
+}
+
+/// All sections carrying `tag` (case-insensitive).
+pub fn with_tag<'a>(tag: &'a str) -> impl Iterator<Item = &'static Section> + 'a {
+    SECTIONS.iter().filter(move |s| s.has_tag(tag))
+}
+
+/// All sections of one sheet.
+pub fn sheet(sheet: Sheet) -> impl Iterator<Item = &'static Section> {
+    SECTIONS.iter().filter(move |s| s.sheet == sheet)
+}
+
+/// Stand-in section ID for the items of [`crate::shared`], which both sheets
+/// import instead of defining.
+pub const SHARED: &str = "shared";
+
+/// Path of the shared module, relative to the workspace root.
+pub const SHARED_FILE: &str = "crates/cheat_sheet/src/shared.rs";
+
+/// Full text of the shared module.
+pub const SHARED_SOURCE: &str = include_str!("shared.rs");
+
+/// The file and text an item's `section` ID points at: a section's module,
+/// or the shared module for [`SHARED`].
+pub fn home(id: &str) -> Option<(&'static str, &'static str)> {
+    if id == SHARED {
+        return Some((SHARED_FILE, SHARED_SOURCE));
+    }
+    find(id).map(|s| (s.file, s.source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids_are_unique() {
+        let mut seen = HashSet::new();
+        for s in registry() {
+            assert!(seen.insert(s.id), "duplicate id {}", s.id);
+        }
+    }
+
+    #[test]
+    fn numbers_follow_sheet_order() {
+        for sh in [Sheet::Basics, Sheet::Types] {
+            let numbers: Vec<u32> = sheet(sh).map(|s| s.number).collect();
+            let expected: Vec<u32> = (1..=numbers.len() as u32).collect();
+            assert_eq!(numbers, expected, "{sh}");
+        }
+    }
+
+    #[test]
+    fn source_is_the_section_module() {
+        for s in registry() {
+            assert!(s.source.contains("pub fn run()"), "{}", s.id);
+            assert!(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("../..")
+                    .join(s.file)
+                    .is_file(),
+                "{}",
+                s.file
+            );
+        }
+    }
+
+    #[test]
+    fn header_tags_are_present() {
+        for tag in ["OWNERSHIP", "STRINGS", "VEC", "HASHMAP", "RESULT", "TRAITS"] {
+            assert!(with_tag(tag).any(|s| s.sheet == Sheet::Basics), "{tag}");
+        }
+        let vecs: Vec<_> = with_tag("vec").map(|s| s.id).collect();
+        assert_eq!(vecs, ["vec", "types/vec"]);
+    }
+
+    #[test]
+    fn find_by_id() {
+        assert_eq!(find("ownership").map(|s| s.number), Some(4));
+        assert_eq!(find("types/tuples").map(|s| s.title), Some("Tuples"));
+        assert!(find("nope").is_none());
+    }
+
+    #[test]
+    fn resolve_falls_back_to_tags() {
+        assert_eq!(resolve("vec").map(|s| s.id), Some("vec"));
+        assert_eq!(resolve("Types/Vec").map(|s| s.id), Some("types/vec"));
+        assert_eq!(resolve("RESULT").map(|s| s.id), Some("result"));
+        assert_eq!(resolve("hashset").map(|s| s.id), Some("types/hashmap"));
+        assert!(resolve("nope").is_none());
+    }
+
+    #[test]
+    fn matching_lines_are_numbered() {
+        let s = find("vec").unwrap();
+        let hits: Vec<_> = s.matching_lines("PUSH").collect();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].1.contains("nums.push(4);"));
+        assert_eq!(s.source.lines().nth(hits[0].0 - 1), Some(hits[0].1));
+    }
+}
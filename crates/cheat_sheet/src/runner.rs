@@ -0,0 +1,269 @@
+//! An offline stand-in for the Rust playground.
+//!
+//! [`build_and_run`] compiles a program with the local `rustc` and runs it
+//! with a time limit and a cap on how much output is kept, and [`diff`]
+//! compares what it printed with the original section's output.
+//!
+//! This is not a sandbox: the program runs as the current user, with full
+//! access to the filesystem and network. The time limit and the output cap
+//! are the only limits, so only run code you'd run yourself.
+
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::compile_fail::{rustc, scratch_dir};
+
+/// How long a program may run and how much of its output is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Duration,
+    /// Bytes kept per stream; the rest is read and dropped.
+    pub max_output: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            timeout: Duration::from_secs(5),
+            max_output: 64 * 1024,
+        }
+    }
+}
+
+/// What a limited run of a process produced.
+#[derive(Debug, Clone)]
+pub struct Output {
+    /// `None` when it was killed at the time limit.
+    pub status: Option<ExitStatus>,
+    pub stdout: String,
+    pub stderr: String,
+    /// `true` when a stream went over [`Limits::max_output`].
+    pub truncated: bool,
+}
+
+impl Output {
+    pub fn timed_out(&self) -> bool {
+        self.status.is_none()
+    }
+
+    pub fn success(&self) -> bool {
+        self.status.is_some_and(|s| s.success())
+    }
+}
+
+/// The result of [`build_and_run`].
+#[derive(Debug, Clone)]
+pub enum Run {
+    /// rustc rejected the program; its diagnostics.
+    CompileError(String),
+    Ran(Output),
+}
+
+/// How long the output is still read after the program exits or is killed.
+const DRAIN: Duration = Duration::from_millis(100);
+
+/// Runs `command` under `limits`, reading its output as it goes so a chatty
+/// program can't block on a full pipe.
+///
+/// A process the program started in the background keeps the pipes open after
+/// the program is gone; its output is read until the time limit, then left.
+pub fn run(command: &mut Command, limits: Limits) -> io::Result<Output> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let deadline = Instant::now() + limits.timeout;
+    let stdout = reader(child.stdout.take(), limits.max_output);
+    let stderr = reader(child.stderr.take(), limits.max_output);
+    let status = wait(&mut child, deadline)?;
+    let until = deadline.max(Instant::now() + DRAIN);
+    let (stdout, out_cut) = stdout.collect(until)?;
+    let (stderr, err_cut) = stderr.collect(until)?;
+    Ok(Output {
+        status,
+        stdout,
+        stderr,
+        truncated: out_cut || err_cut,
+    })
+}
+
+/// What a reader thread has kept of a stream so far.
+#[derive(Default)]
+struct Captured {
+    kept: Vec<u8>,
+    truncated: bool,
+}
+
+struct Reader {
+    captured: Arc<Mutex<Captured>>,
+    thread: JoinHandle<io::Result<()>>,
+}
+
+impl Reader {
+    /// Waits for the stream to end, or until `until`, and returns what was
+    /// read. A thread still reading then is detached (it ends with the pipe).
+    fn collect(self, until: Instant) -> io::Result<(String, bool)> {
+        while !self.thread.is_finished() && Instant::now() < until {
+            thread::sleep(Duration::from_millis(10));
+        }
+        if self.thread.is_finished() {
+            self.thread.join().expect("output reader")?;
+        }
+        let captured = self.captured.lock().unwrap_or_else(|e| e.into_inner());
+        Ok((
+            String::from_utf8_lossy(&captured.kept).into_owned(),
+            captured.truncated,
+        ))
+    }
+}
+
+fn reader(stream: Option<impl Read + Send + 'static>, limit: usize) -> Reader {
+    let captured = Arc::new(Mutex::new(Captured::default()));
+    let shared = Arc::clone(&captured);
+    let thread = thread::spawn(move || {
+        let Some(mut stream) = stream else {
+            return Ok(());
+        };
+        let mut buf = [0; 8192];
+        loop {
+            let n = match stream.read(&mut buf) {
+                Ok(0) => return Ok(()),
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            let mut captured = shared.lock().unwrap_or_else(|e| e.into_inner());
+            let room = limit.saturating_sub(captured.kept.len()).min(n);
+            captured.kept.extend_from_slice(&buf[..room]);
+            captured.truncated |= room < n;
+        }
+    });
+    Reader { captured, thread }
+}
+
+fn wait(child: &mut Child, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Compiles `source` as a binary with the local `rustc` and runs it.
+pub fn build_and_run(source: &str, limits: Limits) -> io::Result<Run> {
+    let dir = scratch_dir()?;
+    let result = build_and_run_in(&dir, source, limits);
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+fn build_and_run_in(dir: &Path, source: &str, limits: Limits) -> io::Result<Run> {
+    let file = dir.join("main.rs");
+    let binary = dir.join("main");
+    std::fs::write(&file, source)?;
+    let compiled = Command::new(rustc())
+        .args(["--edition=2021", "--crate-type=bin", "--crate-name=main"])
+        .args(["--error-format=short", "-A", "warnings", "-o"])
+        .arg(&binary)
+        .arg(&file)
+        .output()?;
+    if !compiled.status.success() {
+        let errors = String::from_utf8_lossy(&compiled.stderr)
+            .replace(&file.display().to_string(), "main.rs");
+        return Ok(Run::CompileError(errors));
+    }
+    run(Command::new(&binary).env("RUST_BACKTRACE", "0"), limits).map(Run::Ran)
+}
+
+/// One line of a [`diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diff<'a> {
+    Same(&'a str),
+    /// Only in the original output.
+    Removed(&'a str),
+    /// Only in the new output.
+    Added(&'a str),
+}
+
+impl std::fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diff::Same(l) => write!(f, "  {l}"),
+            Diff::Removed(l) => write!(f, "- {l}"),
+            Diff::Added(l) => write!(f, "+ {l}"),
+        }
+    }
+}
+
+/// A line diff of `old` against `new` (longest common subsequence).
+pub fn diff<'a>(old: &'a str, new: &'a str) -> Vec<Diff<'a>> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    // lcs[i][j]: common lines of a[i..] and b[j..].
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::new();
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            out.push(Diff::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(Diff::Removed(a[i]));
+            i += 1;
+        } else {
+            out.push(Diff::Added(b[j]));
+            j += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        let d = diff("a\nb\nc\n", "a\nB\nc\nd\n");
+        assert_eq!(
+            d,
+            [
+                Diff::Same("a"),
+                Diff::Removed("b"),
+                Diff::Added("B"),
+                Diff::Same("c"),
+                Diff::Added("d"),
+            ]
+        );
+        let lines: Vec<String> = d.iter().map(|l| l.to_string()).collect();
+        assert_eq!(lines, ["  a", "- b", "+ B", "  c", "+ d"]);
+    }
+
+    #[test]
+    fn identical_output_has_no_changes() {
+        let d = diff("x=1\ny=2\n", "x=1\ny=2\n");
+        assert!(d.iter().all(|l| matches!(l, Diff::Same(_))));
+        assert_eq!(diff("", "new\n"), [Diff::Added("new")]);
+    }
+}
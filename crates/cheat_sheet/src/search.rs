@@ -0,0 +1,329 @@
+//! Ranked search over both sheets.
+//!
+//! Every section is indexed as one document made of its title and tags, the
+//! names of the helpers it defines, the identifiers and string words of its
+//! code, and the words of its comments. Identifiers are also split into their
+//! parts, so `or_insert` is found by `or insert` and `HashMap` by `map`, and a
+//! lifetime like `'a` counts as the word `lifetime`.
+//!
+//! Results are ranked with BM25 (title, tag and helper-name hits weigh more).
+//! A query word that isn't in the index still matches words it is a prefix of
+//! or is one or two typos away from, at a discount, so `lifetim` and
+//! `lifetmes` both find `pick_longer`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::OnceLock;
+
+use crate::export::highlight::{line_tokens, Class};
+use crate::outline;
+use crate::registry::{self, Section};
+
+/// BM25 term-frequency saturation and length normalization.
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+/// How much an occurrence in each part of a section counts.
+const TITLE_WEIGHT: f64 = 3.0;
+const ITEM_WEIGHT: f64 = 2.0;
+const TEXT_WEIGHT: f64 = 1.0;
+
+/// Matching lines shown per hit.
+const MAX_LINES: usize = 5;
+
+/// A section matching a query.
+#[derive(Debug, Clone)]
+pub struct Hit {
+    pub section: &'static Section,
+    pub score: f64,
+    /// Every indexed word of the section the query matched, exact or fuzzy.
+    pub terms: Vec<String>,
+    /// Helper items mentioning a matched word, e.g. `fn pick_longer`.
+    pub items: Vec<String>,
+    /// Source lines mentioning a matched word, with 1-based line numbers.
+    pub lines: Vec<(usize, &'static str)>,
+}
+
+struct Doc {
+    section: &'static Section,
+    /// Weighted length, for BM25's length normalization.
+    len: f64,
+}
+
+/// An inverted index over some sections.
+pub struct Index {
+    docs: Vec<Doc>,
+    /// Word -> (document, weighted frequency).
+    postings: BTreeMap<String, Vec<(usize, f64)>>,
+    avg_len: f64,
+}
+
+impl Index {
+    pub fn new(sections: impl IntoIterator<Item = &'static Section>) -> Self {
+        let mut docs = Vec::new();
+        let mut postings: BTreeMap<String, Vec<(usize, f64)>> = BTreeMap::new();
+        for (doc, section) in sections.into_iter().enumerate() {
+            let mut counts: BTreeMap<String, f64> = BTreeMap::new();
+            let mut add = |terms: Vec<String>, weight: f64| {
+                for term in terms {
+                    *counts.entry(term).or_default() += weight;
+                }
+            };
+            add(words(section.title), TITLE_WEIGHT);
+            for tag in section.tags {
+                add(words(tag), TITLE_WEIGHT);
+            }
+            for item in outline::outline(section).items {
+                add(words(item.name), ITEM_WEIGHT);
+            }
+            for line in section.source.lines() {
+                add(line_terms(line), TEXT_WEIGHT);
+            }
+            let len = counts.values().sum();
+            for (term, tf) in counts {
+                postings.entry(term).or_default().push((doc, tf));
+            }
+            docs.push(Doc { section, len });
+        }
+        let avg_len = docs.iter().map(|d| d.len).sum::<f64>() / docs.len().max(1) as f64;
+        Index {
+            docs,
+            postings,
+            avg_len,
+        }
+    }
+
+    /// Sections matching `query`, best first. Each query word scores its
+    /// best match in a section (itself, or the closest fuzzy word), so a
+    /// prefix matching many words doesn't count many times.
+    pub fn search(&self, query: &str) -> Vec<Hit> {
+        let mut scores = vec![0.0; self.docs.len()];
+        let mut matched: Vec<BTreeSet<&str>> = vec![BTreeSet::new(); self.docs.len()];
+        for word in dedup(words(query)) {
+            let mut best = vec![0.0f64; self.docs.len()];
+            for (term, similarity) in self.expand(&word) {
+                let postings = &self.postings[term];
+                let n = self.docs.len() as f64;
+                let df = postings.len() as f64;
+                let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+                for &(doc, tf) in postings {
+                    let norm = K1 * (1.0 - B + B * self.docs[doc].len / self.avg_len);
+                    let score = similarity * idf * tf * (K1 + 1.0) / (tf + norm);
+                    best[doc] = best[doc].max(score);
+                    matched[doc].insert(term);
+                }
+            }
+            for (doc, best) in best.into_iter().enumerate() {
+                scores[doc] += best;
+            }
+        }
+
+        let mut hits: Vec<Hit> = self
+            .docs
+            .iter()
+            .zip(scores)
+            .zip(matched)
+            .filter(|((_, score), _)| *score > 0.0)
+            .map(|((doc, score), terms)| hit(doc.section, score, terms))
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits
+    }
+
+    /// Indexed words matching `word`, with how much a match counts (1 for
+    /// the word itself).
+    fn expand(&self, word: &str) -> Vec<(&str, f64)> {
+        let typos = match word.chars().count() {
+            0..=3 => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+        self.postings
+            .keys()
+            .filter_map(|term| {
+                let similarity = if term == word {
+                    1.0
+                } else if word.len() >= 4 && term.starts_with(word) {
+                    0.8
+                } else {
+                    match distance(word, term) {
+                        d if d <= typos => 1.0 - 0.3 * d as f64,
+                        _ => return None,
+                    }
+                };
+                Some((term.as_str(), similarity))
+            })
+            .collect()
+    }
+}
+
+fn hit(section: &'static Section, score: f64, terms: BTreeSet<&str>) -> Hit {
+    let mentions = |text: &str| line_terms(text).iter().any(|t| terms.contains(t.as_str()));
+    let items = outline::outline(section)
+        .items
+        .iter()
+        .filter(|item| item.source.lines().any(mentions))
+        .map(|item| item.label())
+        .collect();
+    let lines = section
+        .source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| mentions(line))
+        .map(|(i, line)| (i + 1, line))
+        .take(MAX_LINES)
+        .collect();
+    Hit {
+        section,
+        score,
+        terms: terms.into_iter().map(String::from).collect(),
+        items,
+        lines,
+    }
+}
+
+/// The index over every section, built on first use.
+pub fn index() -> &'static Index {
+    static INDEX: OnceLock<Index> = OnceLock::new();
+    INDEX.get_or_init(|| Index::new(registry::registry()))
+}
+
+/// Searches every section; see [`Index::search`].
+pub fn search(query: &str) -> Vec<Hit> {
+    index().search(query)
+}
+
+/// Lowercase words of `text`: each identifier-like run, plus its
+/// snake_case and CamelCase parts.
+pub fn words(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for word in text
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|w| !w.is_empty())
+    {
+        let parts = parts(word);
+        if parts.len() != 1 {
+            out.push(word.to_lowercase());
+        }
+        out.extend(parts);
+    }
+    out
+}
+
+/// `or_insert` -> `or`, `insert`; `HashMap` -> `hash`, `map`; `i32` -> `i32`.
+fn parts(word: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for piece in word.split('_').filter(|p| !p.is_empty()) {
+        let mut current = String::new();
+        let mut prev_lower = false;
+        for c in piece.chars() {
+            if c.is_uppercase() && prev_lower {
+                out.push(std::mem::take(&mut current));
+            }
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+            current.extend(c.to_lowercase());
+        }
+        out.push(current);
+    }
+    out
+}
+
+/// The indexed words of one source line. Lifetimes count as `lifetime`;
+/// numbers and punctuation are skipped.
+fn line_terms(line: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for (class, range) in line_tokens(line) {
+        match class {
+            Class::Lifetime => out.push("lifetime".to_string()),
+            Class::Number | Class::Plain => {}
+            _ => out.extend(words(&line[range])),
+        }
+    }
+    out
+}
+
+fn dedup(mut words: Vec<String>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    words.retain(|w| seen.insert(w.clone()));
+    words
+}
+
+/// Levenshtein distance between `a` and `b`, in characters.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = prev + usize::from(ca != cb);
+            prev = row[j + 1];
+            row[j + 1] = substitute.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(query: &str) -> Vec<&'static str> {
+        search(query).iter().map(|h| h.section.id).collect()
+    }
+
+    #[test]
+    fn identifiers_split_into_parts() {
+        assert_eq!(
+            words("m.entry(\"b\").or_insert(2)"),
+            ["m", "entry", "b", "or_insert", "or", "insert", "2"]
+        );
+        assert_eq!(words("Box<dyn Speak>"), ["box", "dyn", "speak"]);
+        assert_eq!(words("HashMap i32"), ["hashmap", "hash", "map", "i32"]);
+        assert_eq!(
+            line_terms("fn f<'a>(x: &'a str) {} // 42"),
+            ["fn", "f", "lifetime", "x", "lifetime", "str", "42"]
+        );
+    }
+
+    #[test]
+    fn levenshtein() {
+        assert_eq!(distance("lifetmes", "lifetimes"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+        assert_eq!(distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn or_insert_finds_both_hashmap_demos() {
+        let hits = search("or insert");
+        let top: BTreeSet<_> = hits[..2].iter().map(|h| h.section.id).collect();
+        assert_eq!(top, BTreeSet::from(["hashmap", "types/hashmap"]));
+        assert!(hits[0]
+            .lines
+            .iter()
+            .any(|(_, l)| l.contains(".or_insert(2)")));
+        assert_eq!(ids("or_insert")[..2], ids("or insert")[..2]);
+    }
+
+    #[test]
+    fn prefixes_and_typos_still_match() {
+        for query in ["lifetim", "lifetmes", "pick_longr"] {
+            let hits = search(query);
+            assert_eq!(hits[0].section.id, "lifetimes", "{query}");
+            assert!(
+                hits[0].items.contains(&"fn pick_longer".to_string()),
+                "{query}"
+            );
+        }
+        // Short words must match exactly.
+        assert!(ids("mpa").is_empty());
+    }
+
+    #[test]
+    fn comments_titles_and_types_are_indexed() {
+        assert_eq!(ids("last expression is return value")[0], "functions");
+        assert_eq!(ids("Trait Object")[0], "types/trait_object");
+        assert_eq!(ids("Box<dyn Speak>")[0], "types/trait_object");
+        assert!(ids("parse").contains(&"result"));
+    }
+}
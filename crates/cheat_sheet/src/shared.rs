@@ -0,0 +1,50 @@
+//! Items both sheets use: the `Msg` enum, the identity function `id` and the
+//! `Speak` trait.
+//!
+//! Each sheet used to define its own copies, and they drifted apart (only one
+//! `Msg` derived `Debug`, only one sheet had `Speak for String`). The sections
+//! now import them from here, and
+//! [`outline::diverging_duplicates`](crate::outline::diverging_duplicates)
+//! flags any item defined twice with different bodies.
+
+// -------------------------
+// Enums + match (basics 9, types STRUCT / ENUM)
+// -------------------------
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "derive", derive(cheat_derive::Speak))]
+pub enum Msg {
+    Quit,
+    Write(String),
+    Move { x: i32, y: i32 },
+}
+
+// -------------------------
+// Generics + Traits (basics 11, types GENERICS / TRAIT OBJECT)
+// -------------------------
+pub fn id<T>(x: T) -> T {
+    x
+}
+
+// `Any` as a supertrait lets a `dyn Speak` be upcast to `dyn Any` and downcast
+// back to its concrete type (types PLUGINS).
+pub trait Speak: std::any::Any {
+    fn speak(&self) -> String;
+
+    // A default method: every impl gets it for free, and may override it.
+    fn shout(&self) -> String {
+        self.speak().to_uppercase()
+    }
+}
+
+impl Speak for i32 {
+    fn speak(&self) -> String {
+        format!("num {self}")
+    }
+}
+
+impl Speak for String {
+    fn speak(&self) -> String {
+        format!("str {self}")
+    }
+}
@@ -0,0 +1,144 @@
+//! Golden-output comparison for the checked-in section snapshots.
+//!
+//! Every section's stdout is stored under `crates/cheat_sheet/snapshots/` and
+//! embedded as [`Section::output`]. Sections that print a `HashMap` or
+//! `HashSet` get an order-insensitive comparison: the entries of each
+//! `{...}` collection are sorted before comparing, so
+//! `map={"b": 2, "a": 1}` matches `map={"a": 1, "b": 2}`.
+//!
+//! Regenerate the files with
+//! `UPDATE_SNAPSHOTS=1 cargo test -p cheat_sheet --test snapshots`.
+
+use crate::registry::Section;
+
+/// Whether `actual` matches the section's checked-in output.
+pub fn matches(section: &Section, actual: &str) -> bool {
+    let unordered = section.unordered_output();
+    normalize(section.output, unordered) == normalize(actual, unordered)
+}
+
+/// Canonical form of some output. With `unordered`, the top-level entries of
+/// every `{...}` collection on a line are sorted; struct literals like
+/// `Point { x: 1.0 }` are left alone.
+pub fn normalize(output: &str, unordered: bool) -> String {
+    if !unordered {
+        return output.to_owned();
+    }
+    output.split_inclusive('\n').map(sort_collections).collect()
+}
+
+fn sort_collections(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(open) = rest.find('{') {
+        let (before, from_brace) = rest.split_at(open);
+        out.push_str(before);
+        // `Name { .. }` is a struct, `={..}` / `: {..}` / `[{..}` are collections.
+        let is_struct = out.ends_with(' ') && !out.trim_end().ends_with(':');
+        match closing_brace(from_brace) {
+            Some(close) if !is_struct => {
+                let mut entries = split_entries(&from_brace[1..close]);
+                entries.sort_unstable();
+                out.push('{');
+                out.push_str(&entries.join(", "));
+                out.push('}');
+                rest = &from_brace[close + 1..];
+            }
+            _ => {
+                out.push('{');
+                rest = &from_brace[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Byte offset of the `}` matching the `{` at the start of `s`.
+fn closing_brace(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_str => escaped = true,
+            '"' => in_str = !in_str,
+            '{' | '[' | '(' if !in_str => depth += 1,
+            '}' | ']' | ')' if !in_str => {
+                depth -= 1;
+                if depth == 0 {
+                    return (c == '}').then_some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits `a, b, c` on top-level `", "`, leaving nested and quoted commas alone.
+fn split_entries(s: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_str => escaped = true,
+            '"' => in_str = !in_str,
+            '{' | '[' | '(' if !in_str => depth += 1,
+            '}' | ']' | ')' if !in_str => depth = depth.saturating_sub(1),
+            ',' if !in_str && depth == 0 => {
+                entries.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !s[start..].trim().is_empty() {
+        entries.push(s[start..].trim());
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered_output_is_untouched() {
+        assert_eq!(
+            normalize("map={\"b\": 2, \"a\": 1}\n", false),
+            "map={\"b\": 2, \"a\": 1}\n"
+        );
+    }
+
+    #[test]
+    fn sorts_map_and_set_entries() {
+        assert_eq!(
+            normalize("map={\"b\": 2, \"a\": 1} set={30, 10, 20}\n", true),
+            "map={\"a\": 1, \"b\": 2} set={10, 20, 30}\n"
+        );
+        assert_eq!(normalize("empty={}\n", true), "empty={}\n");
+    }
+
+    #[test]
+    fn leaves_structs_and_quoted_braces_alone() {
+        let line = "user: User { name: \"b, a\", age: 21 } m={\"x,}\": 1, \"a\": 2}";
+        assert_eq!(
+            normalize(line, true),
+            "user: User { name: \"b, a\", age: 21 } m={\"a\": 2, \"x,}\": 1}"
+        );
+    }
+
+    #[test]
+    fn nested_collections_sort_by_top_level_entry() {
+        assert_eq!(
+            normalize("{\"k\": [2, 1], \"a\": {3, 1}}", true),
+            "{\"a\": {3, 1}, \"k\": [2, 1]}"
+        );
+    }
+}
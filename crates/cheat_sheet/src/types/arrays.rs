@@ -0,0 +1,10 @@
+//! ARRAYS & SLICES
+
+pub fn run() {
+    // =========================
+    // ARRAYS & SLICES
+    // =========================
+    let arr: [i32; 3] = [10, 20, 30]; // fixed-size array
+    let slc: &[i32] = &arr[0..2];     // slice view
+    println!("arr={arr:?} slc={slc:?}"); //=> arr=[10, 20, 30] slc=[10, 20]
+}
@@ -0,0 +1,10 @@
+//! OWNERSHIP CLONES (when needed)
+
+pub fn run() {
+    // =========================
+    // OWNERSHIP CLONES (when needed)
+    // =========================
+    let orig = String::from("data");
+    let copy = orig.clone(); // deep copy
+    println!("{orig} {copy}"); //=> data data
+}
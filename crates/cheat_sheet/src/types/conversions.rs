@@ -0,0 +1,14 @@
+//! COMMON CONVERSIONS
+
+pub fn run() {
+    // =========================
+    // COMMON CONVERSIONS
+    // =========================
+    let num: i32 = "123".parse().unwrap(); // str -> i32
+    let s2: String = num.to_string();      // i32 -> String
+    println!("{num} -> {s2}"); //=> 123 -> 123
+
+    // Into / From (idiomatic)
+    let s3: String = "hey".into();
+    println!("{s3}"); //=> hey
+}
@@ -0,0 +1,12 @@
+//! GENERICS
+
+use crate::shared::id;
+
+pub fn run() {
+    // =========================
+    // GENERICS
+    // =========================
+    let a = id(9);
+    let b = id("hi");
+    println!("id: {a} {b}"); //=> id: 9 hi
+}
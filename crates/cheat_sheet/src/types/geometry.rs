@@ -0,0 +1,157 @@
+//! GEOMETRY: operator overloading on Point<T>
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+pub fn run() {
+    // =========================
+    // GEOMETRY: operator overloading on Point<T>
+    // =========================
+    let a = Point { x: 1, y: 2 };
+    let b: Point<i32> = (3, 4).into(); // From<(T, T)>
+    println!("a+b={:?}", a + b); //=> a+b=Point { x: 4, y: 6 }
+    println!("a-b={:?}", a - b); //=> a-b=Point { x: -2, y: -2 }
+    println!("a*3={:?}", a * 3); //=> a*3=Point { x: 3, y: 6 }
+    println!("-a={:?}", -a); //=> -a=Point { x: -1, y: -2 }
+    println!("a.b={}", a.dot(b)); //=> a.b=11
+
+    // `length` is only implemented where T: Float
+    let v = Point { x: 3.0, y: 4.0 };
+    println!("|v|={}", v.length()); //=> |v|=5
+    // Point { x: 3, y: 4 }.length(); // error[E0599]: `length` exists, but i32 isn't Float
+    // -Point { x: 1u32, y: 2 }; // error[E0600]: u32 has no Neg, so Point<u32> doesn't either
+
+    // Default type parameter: a bare `Point` is `Point<f64>`
+    let origin: Point = Point::default();
+    println!("origin={origin:?}"); //=> origin=Point { x: 0.0, y: 0.0 }
+}
+
+// --------- Point<T> and the traits its coordinates need ---------
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+// Our own numeric trait: a name for the bounds, plus a blanket impl so every
+// type that has them (i32, u8, f64, ...) is a Num without opting in.
+pub trait Num: Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {}
+
+impl<T> Num for T where T: Copy + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T> {}
+
+// Floats also have square roots; here each type opts in.
+pub trait Float: Num {
+    fn sqrt(self) -> Self;
+}
+
+impl Float for f32 {
+    fn sqrt(self) -> f32 {
+        f32::sqrt(self)
+    }
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> f64 {
+        f64::sqrt(self)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Num> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl<T: Num> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+// Scaling: `Point<T> * T` (not `T * Point<T>`, which would need an impl on T).
+impl<T: Num> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, k: T) -> Self::Output {
+        Point { x: self.x * k, y: self.y * k }
+    }
+}
+
+// Only for coordinates that can be negated: not for unsigned ones.
+impl<T: Num + Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Self::Output {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+impl<T: Num> Point<T> {
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl<T: Float> Point<T> {
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Small enough that sums and products can't overflow.
+    fn point() -> impl Strategy<Value = Point<i64>> {
+        (-1_000_000i64..1_000_000, -1_000_000i64..1_000_000).prop_map(Point::from)
+    }
+
+    proptest! {
+        #[test]
+        fn addition_commutes_and_associates(a in point(), b in point(), c in point()) {
+            prop_assert_eq!(a + b, b + a);
+            prop_assert_eq!((a + b) + c, a + (b + c));
+            prop_assert_eq!(a + Point::default(), a);
+        }
+
+        #[test]
+        fn subtraction_undoes_addition(a in point(), b in point()) {
+            prop_assert_eq!(a + b - b, a);
+            prop_assert_eq!(a - b, a + -b);
+            prop_assert_eq!(-(-a), a);
+        }
+
+        #[test]
+        fn scaling_distributes(a in point(), b in point(), k in -1000i64..1000) {
+            prop_assert_eq!((a + b) * k, a * k + b * k);
+            prop_assert_eq!(a.dot(b * k), a.dot(b) * k);
+            prop_assert_eq!(a.dot(b), b.dot(a));
+        }
+
+        #[test]
+        fn length_scales_with_its_factor(x in -1e6f64..1e6, y in -1e6f64..1e6, k in -100f64..100.0) {
+            let v = Point { x, y };
+            let scaled = (v * k).length();
+            prop_assert!((scaled - k.abs() * v.length()).abs() <= 1e-9 * scaled.max(1.0));
+        }
+    }
+
+    #[test]
+    fn lengths_of_known_points() {
+        assert_eq!(Point { x: 3.0f32, y: 4.0 }.length(), 5.0);
+        assert_eq!(Point::<f64>::default().length(), 0.0);
+        assert_eq!(Point::from((6.0, 8.0)).length(), 10.0);
+    }
+}
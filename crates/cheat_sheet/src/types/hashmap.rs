@@ -0,0 +1,18 @@
+//! HASHMAP / HASHSET
+
+use std::collections::{HashMap, HashSet};
+
+pub fn run() {
+    // =========================
+    // HASHMAP / HASHSET
+    // =========================
+    let mut map: HashMap<&str, i32> = HashMap::new();
+    map.insert("a", 1);
+    map.entry("b").or_insert(2);
+
+    let mut set: HashSet<i32> = HashSet::new();
+    set.insert(10);
+    set.insert(10);
+
+    println!("map={map:?} set={set:?}"); //=> map={"a": 1, "b": 2} set={10}
+}
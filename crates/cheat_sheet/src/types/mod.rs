@@ -0,0 +1,53 @@
+//! types_cheat_sheet: Rust's types, each with a tiny example.
+//!
+//! Focus: primitives, strings, slices, tuples, arrays, vecs, options/results,
+//! refs, pointers-ish, structs/enums, generics/traits, conversions.
+
+pub mod primitives;
+pub mod strings;
+pub mod references;
+pub mod unit;
+pub mod tuples;
+pub mod arrays;
+pub mod vec;
+pub mod option_result;
+pub mod string_collections;
+pub mod hashmap;
+pub mod struct_enum;
+pub mod generics;
+pub mod trait_object;
+pub mod conversions;
+pub mod clones;
+pub mod geometry;
+pub mod plugins;
+#[cfg(feature = "serde")]
+pub mod serialization;
+
+pub use crate::shared::{id, Msg, Speak};
+pub use strings::takes_str;
+pub use geometry::{Float, Num, Point};
+pub use plugins::{Plugin, Robot, SpeakerError, SpeakerRegistry};
+pub use struct_enum::describe;
+
+/// Runs every section of this sheet, in order.
+pub fn run() {
+    primitives::run();
+    strings::run();
+    references::run();
+    unit::run();
+    tuples::run();
+    arrays::run();
+    vec::run();
+    option_result::run();
+    string_collections::run();
+    hashmap::run();
+    struct_enum::run();
+    generics::run();
+    trait_object::run();
+    conversions::run();
+    clones::run();
+    geometry::run();
+    plugins::run();
+    #[cfg(feature = "serde")]
+    serialization::run();
+}
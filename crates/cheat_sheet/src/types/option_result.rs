@@ -0,0 +1,14 @@
+//! OPTION / RESULT (very common)
+
+pub fn run() {
+    // =========================
+    // OPTION / RESULT (very common)
+    // =========================
+    let maybe: Option<i32> = Some(7);
+    let none: Option<i32> = None;
+    println!("{maybe:?} {none:?}"); //=> Some(7) None
+
+    let ok: Result<i32, &str> = Ok(42);
+    let err: Result<i32, &str> = Err("nope");
+    println!("{ok:?} {err:?}"); //=> Ok(42) Err("nope")
+}
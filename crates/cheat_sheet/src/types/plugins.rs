@@ -0,0 +1,257 @@
+//! PLUGINS: a trait-object registry built on Speak
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::thread;
+
+use crate::shared::Speak;
+
+pub fn run() {
+    // =========================
+    // PLUGINS: a trait-object registry built on Speak
+    // =========================
+    // Types register themselves under a name; specs like "i32:7" build them.
+    let mut speakers = SpeakerRegistry::with_builtins(); // i32, string
+    speakers.register::<Robot>();
+    println!("kinds: {:?}", speakers.kinds().collect::<Vec<_>>()); //=> kinds: ["i32", "robot", "string"]
+
+    let made: Vec<Arc<dyn Speak + Send + Sync>> = ["i32:7", "string:yo", "robot:42"]
+        .into_iter()
+        .map(|spec| speakers.create(spec))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    // `shout` is a default method; Robot overrides it
+    for s in &made {
+        println!("{} / {}", s.speak(), s.shout()); //=> num 7 / NUM 7
+                                                   //=> str yo / STR YO
+                                                   //=> robot 42 / BEEP BOOP 42
+    }
+
+    // Arc: clones share one value; Send + Sync let the clones cross threads
+    let robot = Arc::clone(&made[2]);
+    let said = thread::spawn(move || robot.speak()).join().unwrap();
+    println!("from a thread: {said}"); //=> from a thread: robot 42
+
+    // Downcasting: from `dyn Speak` back to the concrete type, via Any
+    let id = made[2].downcast_ref::<Robot>().map(|r| r.id);
+    println!("robot id: {id:?}"); //=> robot id: Some(42)
+    println!("is i32: {:?}", made[2].downcast_ref::<i32>()); //=> is i32: None
+    // which upcasts to `dyn Any` (Speak's supertrait) and asks that
+    let any: &dyn Any = &*made[0];
+    println!("{:?}", any.downcast_ref::<i32>()); //=> Some(7)
+
+    // Bad specs are typed errors
+    for spec in ["float:1.5", "i32:seven", "yo"] {
+        if let Err(e) = speakers.create(spec) {
+            println!("{e}"); //=> unknown speaker kind `float` (known: i32, robot, string)
+                             //=> bad argument for `i32`: invalid digit found in string
+                             //=> expected `kind:argument`, got `yo`
+        }
+    }
+}
+
+// -------------------------
+// Plugin: what a type needs to be built by name
+// -------------------------
+pub trait Plugin: Speak + Send + Sync + Sized + 'static {
+    const KIND: &'static str;
+
+    fn parse(arg: &str) -> Result<Self, String>;
+}
+
+impl Plugin for i32 {
+    const KIND: &'static str = "i32";
+
+    fn parse(arg: &str) -> Result<Self, String> {
+        arg.parse().map_err(|e| format!("{e}"))
+    }
+}
+
+impl Plugin for String {
+    const KIND: &'static str = "string";
+
+    fn parse(arg: &str) -> Result<Self, String> {
+        Ok(arg.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Robot {
+    pub id: u32,
+}
+
+impl Speak for Robot {
+    fn speak(&self) -> String {
+        format!("robot {}", self.id)
+    }
+
+    fn shout(&self) -> String {
+        format!("BEEP BOOP {}", self.id)
+    }
+}
+
+impl Plugin for Robot {
+    const KIND: &'static str = "robot";
+
+    fn parse(arg: &str) -> Result<Self, String> {
+        let id = arg.parse().map_err(|_| format!("`{arg}` is not a robot id"))?;
+        Ok(Robot { id })
+    }
+}
+
+// -------------------------
+// The registry: kind name -> constructor
+// -------------------------
+type Make = fn(&str) -> Result<Arc<dyn Speak + Send + Sync>, String>;
+
+#[derive(Default)]
+pub struct SpeakerRegistry {
+    makers: BTreeMap<&'static str, Make>,
+}
+
+impl SpeakerRegistry {
+    pub fn with_builtins() -> Self {
+        let mut registry = SpeakerRegistry::default();
+        registry.register::<i32>();
+        registry.register::<String>();
+        registry
+    }
+
+    // The closure captures nothing, so it coerces to a plain `fn` pointer.
+    pub fn register<T: Plugin>(&mut self) {
+        self.makers.insert(T::KIND, |arg| {
+            let speaker: Arc<dyn Speak + Send + Sync> = Arc::new(T::parse(arg)?);
+            Ok(speaker)
+        });
+    }
+
+    pub fn kinds(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.makers.keys().copied()
+    }
+
+    // "kind:argument" -> a shared speaker
+    pub fn create(&self, spec: &str) -> Result<Arc<dyn Speak + Send + Sync>, SpeakerError> {
+        let (kind, arg) = spec
+            .split_once(':')
+            .ok_or_else(|| SpeakerError::BadSpec(spec.to_string()))?;
+        let make = self.makers.get(kind).ok_or_else(|| SpeakerError::UnknownKind {
+            kind: kind.to_string(),
+            known: self.kinds().collect(),
+        })?;
+        make(arg).map_err(|reason| SpeakerError::BadArgument { kind: kind.to_string(), reason })
+    }
+}
+
+// Like `<dyn Any>::downcast_ref`, but straight from the trait object.
+impl dyn Speak + Send + Sync {
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        (self as &dyn Any).downcast_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpeakerError {
+    BadSpec(String),
+    UnknownKind { kind: String, known: Vec<&'static str> },
+    BadArgument { kind: String, reason: String },
+}
+
+impl fmt::Display for SpeakerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpeakerError::BadSpec(spec) => write!(f, "expected `kind:argument`, got `{spec}`"),
+            SpeakerError::UnknownKind { kind, known } if known.is_empty() => {
+                write!(f, "unknown speaker kind `{kind}` (none are registered)")
+            }
+            SpeakerError::UnknownKind { kind, known } => {
+                write!(f, "unknown speaker kind `{kind}` (known: {})", known.join(", "))
+            }
+            SpeakerError::BadArgument { kind, reason } => {
+                write!(f, "bad argument for `{kind}`: {reason}")
+            }
+        }
+    }
+}
+
+impl Error for SpeakerError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn said(registry: &SpeakerRegistry, spec: &str) -> String {
+        registry.create(spec).unwrap().speak()
+    }
+
+    #[test]
+    fn builtins_are_created_from_specs() {
+        let registry = SpeakerRegistry::with_builtins();
+        assert_eq!(said(&registry, "i32:-3"), "num -3");
+        assert_eq!(said(&registry, "string:a:b"), "str a:b"); // split at the first colon
+        assert_eq!(said(&registry, "string:"), "str ");
+        assert_eq!(registry.kinds().collect::<Vec<_>>(), ["i32", "string"]);
+    }
+
+    #[test]
+    fn unknown_kinds_name_the_known_ones() {
+        let mut registry = SpeakerRegistry::with_builtins();
+        let err = registry.create("robot:1").err().unwrap();
+        assert_eq!(
+            err,
+            SpeakerError::UnknownKind { kind: "robot".into(), known: vec!["i32", "string"] }
+        );
+        assert_eq!(err.to_string(), "unknown speaker kind `robot` (known: i32, string)");
+
+        registry.register::<Robot>();
+        assert_eq!(said(&registry, "robot:1"), "robot 1");
+        let err = SpeakerRegistry::default().create("i32:1").err().unwrap();
+        assert_eq!(err.to_string(), "unknown speaker kind `i32` (none are registered)");
+    }
+
+    #[test]
+    fn bad_specs_and_arguments() {
+        let mut registry = SpeakerRegistry::with_builtins();
+        registry.register::<Robot>();
+        assert_eq!(registry.create("i32").err(), Some(SpeakerError::BadSpec("i32".into())));
+        let err = registry.create("robot:-1").err().unwrap();
+        assert_eq!(err.to_string(), "bad argument for `robot`: `-1` is not a robot id");
+        assert!(matches!(registry.create("i32:99999999999"), Err(SpeakerError::BadArgument { .. })));
+    }
+
+    #[test]
+    fn default_methods_and_overrides() {
+        let mut registry = SpeakerRegistry::with_builtins();
+        registry.register::<Robot>();
+        assert_eq!(registry.create("string:hey").unwrap().shout(), "STR HEY");
+        assert_eq!(registry.create("robot:9").unwrap().shout(), "BEEP BOOP 9");
+    }
+
+    #[test]
+    fn downcasts_find_the_concrete_type() {
+        let mut registry = SpeakerRegistry::with_builtins();
+        registry.register::<Robot>();
+        let robot = registry.create("robot:5").unwrap();
+        assert_eq!(robot.downcast_ref::<Robot>(), Some(&Robot { id: 5 }));
+        assert_eq!(robot.downcast_ref::<String>(), None);
+        let s = registry.create("string:x").unwrap();
+        assert_eq!(s.downcast_ref::<String>().map(String::as_str), Some("x"));
+    }
+
+    #[test]
+    fn speakers_are_shared_across_threads() {
+        let speaker = SpeakerRegistry::with_builtins().create("i32:1").unwrap();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let speaker = Arc::clone(&speaker);
+                thread::spawn(move || speaker.speak())
+            })
+            .collect();
+        for h in handles {
+            assert_eq!(h.join().unwrap(), "num 1");
+        }
+        assert_eq!(Arc::strong_count(&speaker), 1);
+    }
+}
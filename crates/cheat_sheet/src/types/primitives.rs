@@ -0,0 +1,26 @@
+//! PRIMITIVES
+
+#[allow(clippy::approx_constant)] // literal floats, not stand-ins for PI / E
+pub fn run() {
+    // =========================
+    // PRIMITIVES
+    // =========================
+    let b: bool = true;
+
+    let c: char = 'A';            // 4-byte Unicode scalar value
+    let byte: u8 = b'A';          // a single byte literal
+
+    let i: i32 = -123;
+    let u: u64 = 123;
+    let isz: isize = -1;
+    let usz: usize = 10;
+
+    let f1: f32 = 3.14;
+    let f2: f64 = 2.71828;
+
+    println!("{b} {c} {byte} {i} {u} {isz} {usz} {f1} {f2}"); //=> true A 65 -123 123 -1 10 3.14 2.71828
+
+    // Integer families:
+    // signed:  i8 i16 i32 i64 i128 isize
+    // unsigned:u8 u16 u32 u64 u128 usize
+}
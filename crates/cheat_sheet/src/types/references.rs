@@ -0,0 +1,14 @@
+//! REFERENCES & MUTABILITY
+
+pub fn run() {
+    // =========================
+    // REFERENCES & MUTABILITY
+    // =========================
+    let mut n: i32 = 5;
+    let r1: &i32 = &n;          // shared borrow (read-only)
+    println!("r1={r1}");        // last use of r1: the shared borrow ends here
+    let r2: &mut i32 = &mut n;  // exclusive borrow (mutable)
+    *r2 += 1;
+    // println!("{r1}");        // error[E0502]: r1 can't outlive the &mut borrow
+    println!("n={n}"); //=> n=6
+}
@@ -0,0 +1,153 @@
+//! SERDE: JSON / TOML round-trips (needs the `serde` feature)
+
+use serde::{Deserialize, Serialize};
+
+use crate::basics::User;
+use crate::shared::Msg;
+use crate::types::Point;
+
+pub fn run() {
+    // =========================
+    // SERDE: JSON / TOML round-trips
+    // =========================
+    // `#[derive(Serialize, Deserialize)]` (behind `cfg_attr`) on User, Point, Msg
+    let user = User::new("alex", 21);
+    let json = serde_json::to_string(&user).unwrap();
+    println!("{json}"); //=> {"name":"alex","age":21}
+    let back: User = serde_json::from_str(&json).unwrap();
+    println!("same: {}", back == user); //=> same: true
+
+    // TOML documents are tables: one `key = value` per field
+    let p = Point { x: 1.5, y: -2.0 };
+    let toml = toml::to_string(&p).unwrap();
+    println!("{toml:?}"); //=> "x = 1.5\ny = -2.0\n"
+    let back: Point = toml::from_str(&toml).unwrap();
+    println!("{back:?}"); //=> Point { x: 1.5, y: -2.0 }
+
+    // Missing or mistyped fields are errors, not defaults
+    let bad = serde_json::from_str::<User>(r#"{"name":"alex"}"#).unwrap_err();
+    println!("{bad}"); //=> missing field `age` at line 1 column 15
+
+    // Enum shapes for the same `Move { x: 3, y: 4 }`:
+    // externally tagged (the default): { variant: fields }
+    let m = Msg::Move { x: 3, y: 4 };
+    println!("{}", serde_json::to_string(&m).unwrap()); //=> {"Move":{"x":3,"y":4}}
+    // internally tagged `#[serde(tag = "type")]`: the tag joins the fields
+    println!("{}", serde_json::to_string(&Internal::Move { x: 3, y: 4 }).unwrap()); //=> {"type":"Move","x":3,"y":4}
+    // adjacently tagged `#[serde(tag = "t", content = "c")]`: tag and fields side by side
+    println!("{}", serde_json::to_string(&Adjacent::Move { x: 3, y: 4 }).unwrap()); //=> {"t":"Move","c":{"x":3,"y":4}}
+    // untagged `#[serde(untagged)]`: just the fields, the variant name is gone
+    println!("{}", serde_json::to_string(&Untagged::Move { x: 3, y: 4 }).unwrap()); //=> {"x":3,"y":4}
+
+    // The other variants, externally tagged:
+    for m in [Msg::Quit, Msg::Write("hi".into())] {
+        println!("{}", serde_json::to_string(&m).unwrap()); //=> "Quit"
+                                                             //=> {"Write":"hi"}
+    }
+    // Internal tags need a map to live in, so `Write(String)` can't have one:
+    let err = serde_json::to_string(&Internal::Write("hi".into())).unwrap_err();
+    println!("{err}"); //=> cannot serialize tagged newtype variant Internal::Write containing a string
+    // Untagged deserializing tries each variant in order; first fit wins
+    let u: Vec<Untagged> = serde_json::from_str(r#"[null, "hi", {"x": 3, "y": 4}]"#).unwrap();
+    println!("{u:?}"); //=> [Quit, Write("hi"), Move { x: 3, y: 4 }]
+
+    // In TOML the same shapes become tables
+    println!("{:?}", toml::to_string(&m).unwrap()); //=> "[Move]\nx = 3\ny = 4\n"
+    println!("{:?}", toml::to_string(&Internal::Move { x: 3, y: 4 }).unwrap()); //=> "type = \"Move\"\nx = 3\ny = 4\n"
+    let back: Msg = toml::from_str("[Move]\nx = 3\ny = 4\n").unwrap();
+    println!("{back:?}"); //=> Move { x: 3, y: 4 }
+}
+
+// --------- Msg's variants with other enum representations ---------
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Internal {
+    Quit,
+    Write(String),
+    Move { x: i32, y: i32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+pub enum Adjacent {
+    Quit,
+    Write(String),
+    Move { x: i32, y: i32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Untagged {
+    Quit,
+    Write(String),
+    Move { x: i32, y: i32 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn msg() -> impl Strategy<Value = Msg> {
+        prop_oneof![
+            Just(Msg::Quit),
+            any::<String>().prop_map(Msg::Write),
+            any::<(i32, i32)>().prop_map(|(x, y)| Msg::Move { x, y }),
+        ]
+    }
+
+    fn user() -> impl Strategy<Value = User> {
+        (any::<String>(), any::<u32>()).prop_map(|(name, age)| User { name, age })
+    }
+
+    fn json<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+        serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+    }
+
+    fn toml<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+        toml::from_str(&toml::to_string(value).unwrap()).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn msg_round_trips_through_json(m in msg()) {
+            prop_assert_eq!(json(&m), m);
+        }
+
+        #[test]
+        fn every_representation_round_trips(m in msg()) {
+            let (adjacent, untagged) = match m.clone() {
+                Msg::Quit => (Adjacent::Quit, Untagged::Quit),
+                Msg::Write(s) => (Adjacent::Write(s.clone()), Untagged::Write(s)),
+                Msg::Move { x, y } => (Adjacent::Move { x, y }, Untagged::Move { x, y }),
+            };
+            prop_assert_eq!(json(&adjacent), adjacent);
+            prop_assert_eq!(json(&untagged), untagged);
+            match m {
+                Msg::Quit => prop_assert_eq!(json(&Internal::Quit), Internal::Quit),
+                Msg::Write(s) => prop_assert!(serde_json::to_string(&Internal::Write(s)).is_err()),
+                Msg::Move { x, y } => {
+                    prop_assert_eq!(json(&Internal::Move { x, y }), Internal::Move { x, y })
+                }
+            }
+        }
+
+        #[test]
+        fn user_round_trips_through_json_and_toml(u in user()) {
+            prop_assert_eq!(json(&u), u.clone());
+            prop_assert_eq!(toml(&u), u);
+        }
+
+        #[test]
+        fn point_round_trips(x in any::<i64>(), y in any::<i64>(), fx in prop::num::f64::NORMAL, fy in prop::num::f64::NORMAL) {
+            prop_assert_eq!(json(&Point { x, y }), Point { x, y });
+            prop_assert_eq!(toml(&Point { x: fx, y: fy }), Point { x: fx, y: fy });
+        }
+
+        #[test]
+        fn moves_round_trip_through_toml(x in any::<i32>(), y in any::<i32>()) {
+            let m = Msg::Move { x, y };
+            prop_assert_eq!(toml(&m), m);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! STRING/SLICE COLLECTIONS
+
+pub fn run() {
+    // =========================
+    // STRING/SLICE COLLECTIONS
+    // =========================
+    // Vec<&str> (borrowed string slices)
+    let words: Vec<&str> = vec!["a", "b", "c"];
+
+    // Vec<String> (owned strings)
+    let owned_words: Vec<String> = words.iter().map(|w| (*w).to_string()).collect();
+
+    println!("{words:?} {owned_words:?}"); //=> ["a", "b", "c"] ["a", "b", "c"]
+}
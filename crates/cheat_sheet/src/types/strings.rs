@@ -0,0 +1,19 @@
+//! STRINGS
+
+pub fn run() {
+    // =========================
+    // STRINGS
+    // =========================
+    let s_str: &str = "hello";           // string slice (borrowed)
+    let mut s: String = "hi".to_string(); // owned, growable
+    s.push_str(" there");
+    println!("{s_str} | {s}"); //=> hello | hi there
+
+    // &String coerces to &str automatically in many places
+    takes_str(&s);
+}
+
+// --------- helpers / types ---------
+pub fn takes_str(s: &str) {
+    println!("takes_str: {s}");
+}
@@ -0,0 +1,25 @@
+//! STRUCT / ENUM
+
+use crate::shared::Msg;
+use crate::types::geometry::Point;
+
+pub fn run() {
+    // =========================
+    // STRUCT / ENUM
+    // =========================
+    let p = Point { x: 1.0, y: 2.0 };
+    println!("point={p:?}"); //=> point=Point { x: 1.0, y: 2.0 }
+    // Point<T> gets operators and more in GEOMETRY
+
+    let msg = Msg::Move { x: 3, y: 4 };
+    println!("msg={:?}", describe(msg)); //=> msg="move"
+}
+
+// --------- helpers / types ---------
+pub fn describe(m: Msg) -> &'static str {
+    match m {
+        Msg::Quit => "quit",
+        Msg::Write(_) => "write",
+        Msg::Move { .. } => "move",
+    }
+}
@@ -0,0 +1,15 @@
+//! TRAIT OBJECT (dynamic dispatch)
+
+use crate::shared::Speak;
+
+pub fn run() {
+    // =========================
+    // TRAIT OBJECT (dynamic dispatch)
+    // =========================
+    let things: Vec<Box<dyn Speak>> = vec![Box::new(7i32), Box::new(String::from("yo"))];
+    for t in things {
+        println!("speak: {}", t.speak()); //=> speak: num 7
+                                          //=> speak: str yo
+    }
+    // Built from strings like "i32:7" at runtime instead: PLUGINS
+}
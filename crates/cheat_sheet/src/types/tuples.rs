@@ -0,0 +1,11 @@
+//! TUPLES
+
+pub fn run() {
+    // =========================
+    // TUPLES
+    // =========================
+    let t: (i32, &str, bool) = (1, "x", true);
+    let (a, b2, c2) = t;
+    println!("{a} {b2} {c2}"); //=> 1 x true
+    println!("t.0 = {}", t.0); //=> t.0 = 1
+}
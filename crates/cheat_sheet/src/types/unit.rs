@@ -0,0 +1,10 @@
+//! UNIT TYPE
+
+#[allow(clippy::let_unit_value)] // binding `()` is the whole point here
+pub fn run() {
+    // =========================
+    // UNIT TYPE
+    // =========================
+    let unit: () = ();
+    println!("{unit:?}"); //=> ()
+}
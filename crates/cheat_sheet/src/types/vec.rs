@@ -0,0 +1,10 @@
+//! VEC (growable array)
+
+pub fn run() {
+    // =========================
+    // VEC (growable array)
+    // =========================
+    let mut v: Vec<i32> = vec![1, 2, 3];
+    v.push(4);
+    println!("v={v:?}"); //=> v=[1, 2, 3, 4]
+}
@@ -0,0 +1,71 @@
+//! Checks every commented-out error line against the real compiler.
+
+use cheat_sheet::compile_fail::{self, compile, standalone};
+use cheat_sheet::quiz::{self, Kind};
+use cheat_sheet::{explain, registry};
+
+#[test]
+fn commented_out_lines_fail_with_the_claimed_code() {
+    let cases = compile_fail::cases();
+    assert!(!cases.is_empty());
+    for case in cases {
+        let compiled = case.check().expect("run rustc");
+        assert!(
+            compiled.failed_with(case.expected),
+            "{}:{}: `{}` claims {} but rustc reported {:?}\n{}",
+            case.section.file,
+            case.line,
+            case.code,
+            case.expected,
+            compiled.codes,
+            compiled.stderr
+        );
+    }
+}
+
+#[test]
+fn every_section_compiles_standalone() {
+    for section in registry::registry().iter().filter(|s| !s.needs_cargo()) {
+        let compiled = compile(&standalone(section)).expect("run rustc");
+        assert!(compiled.ok, "{}:\n{}", section.file, compiled.stderr);
+    }
+}
+
+#[test]
+fn explain_examples_fail_and_pass_as_claimed() {
+    for e in explain::explanations() {
+        let failing = compile(e.failing).expect("run rustc");
+        assert!(
+            failing.failed_with(e.code),
+            "{}: failing example reported {:?}\n{}",
+            e.code,
+            failing.codes,
+            failing.stderr
+        );
+        let passing = compile(e.passing).expect("run rustc");
+        assert!(
+            passing.ok,
+            "{}: passing example:\n{}",
+            e.code, passing.stderr
+        );
+    }
+}
+
+#[test]
+fn quiz_compile_questions_grade_against_rustc() {
+    for q in quiz::questions(registry::registry()) {
+        let Kind::Compiles { claimed, .. } = q.kind else {
+            continue;
+        };
+        let reply = if claimed.is_some() { "n" } else { "y" };
+        let grade = q.grade(reply).expect("run rustc");
+        assert!(
+            grade.correct,
+            "{}:{}: {}",
+            q.section.file, q.line, grade.answer
+        );
+        if let Some(code) = claimed {
+            assert_eq!(grade.answer, format!("no: rustc reports {code}"));
+        }
+    }
+}
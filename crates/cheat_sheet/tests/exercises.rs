@@ -0,0 +1,53 @@
+//! Grades every exercise's stub and reference solution with the hidden tests.
+
+use cheat_sheet::exercise::{self, check, Outcome};
+
+#[test]
+fn reference_solutions_pass_every_case() {
+    for e in exercise::exercises() {
+        let report = check(e, &e.solution()).expect("run rustc");
+        assert!(
+            report.compile_errors.is_none(),
+            "{}: {:?}",
+            e.section,
+            report.compile_errors
+        );
+        assert!(report.all_passed(), "{}: {:?}", e.section, report.cases);
+    }
+}
+
+#[test]
+fn stubs_pass_nothing() {
+    for e in exercise::exercises() {
+        let report = check(e, &e.stub()).expect("run rustc");
+        assert_eq!(report.passed(), 0, "{}: {:?}", e.section, report.cases);
+        if e.strip_lifetimes {
+            let errors = report
+                .compile_errors
+                .expect("lifetimes stub should not compile");
+            assert!(errors.contains("E0106"), "{errors}");
+            assert!(report.cases.iter().all(|(_, o)| *o == Outcome::NotRun));
+        } else {
+            assert!(
+                report.compile_errors.is_none(),
+                "{:?}",
+                report.compile_errors
+            );
+        }
+    }
+}
+
+#[test]
+fn a_partial_answer_fails_only_its_cases() {
+    let e = exercise::find("result").unwrap();
+    let lib =
+        "pub fn maybe_pos(n: i32) -> Option<i32> {\n    if n >= 0 { Some(n) } else { None }\n}\n";
+    let report = check(e, lib).expect("run rustc");
+    let failed: Vec<_> = report
+        .cases
+        .iter()
+        .filter(|(_, o)| *o == Outcome::Failed)
+        .map(|(c, _)| c.name)
+        .collect();
+    assert_eq!(failed, ["zero_is_none"]);
+}
@@ -0,0 +1,84 @@
+//! Builds and runs programs through the limited runner.
+
+use std::time::{Duration, Instant};
+
+use cheat_sheet::compile_fail::standalone;
+use cheat_sheet::registry;
+use cheat_sheet::runner::{build_and_run, Limits, Run};
+
+fn ran(source: &str, limits: Limits) -> cheat_sheet::runner::Output {
+    match build_and_run(source, limits).expect("run rustc") {
+        Run::Ran(output) => output,
+        Run::CompileError(errors) => panic!("{errors}"),
+    }
+}
+
+#[test]
+fn a_section_runs_like_the_original() {
+    let section = registry::find("ownership").unwrap();
+    let output = ran(&standalone(section), Limits::default());
+    assert!(output.success());
+    assert_eq!(output.stdout, section.output);
+}
+
+#[test]
+fn compile_errors_are_reported() {
+    let Run::CompileError(errors) =
+        build_and_run("fn main() { let x: i32 = \"no\"; }", Limits::default()).unwrap()
+    else {
+        panic!("should not compile");
+    };
+    assert!(errors.starts_with("main.rs:1:"), "{errors}");
+    assert!(errors.contains("E0308"), "{errors}");
+}
+
+#[test]
+fn endless_programs_are_stopped() {
+    let limits = Limits {
+        timeout: Duration::from_millis(500),
+        ..Limits::default()
+    };
+    let start = Instant::now();
+    let output = ran("fn main() { println!(\"start\"); loop {} }", limits);
+    assert!(output.timed_out());
+    assert!(!output.success());
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+// The background `sleep` holds on to stdout after the program is killed.
+#[cfg(unix)]
+#[test]
+fn background_processes_dont_outlive_the_timeout() {
+    let limits = Limits {
+        timeout: Duration::from_millis(500),
+        ..Limits::default()
+    };
+    let start = Instant::now();
+    let output = ran(
+        "fn main() {\n    std::process::Command::new(\"sleep\").arg(\"30\").spawn().unwrap();\n    println!(\"started\");\n    loop {}\n}",
+        limits,
+    );
+    assert!(output.timed_out());
+    assert_eq!(output.stdout, "started\n");
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "{:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn output_is_capped() {
+    let limits = Limits {
+        max_output: 100,
+        ..Limits::default()
+    };
+    let output = ran(
+        "fn main() { for i in 0..10_000 { println!(\"line {i}\"); } }",
+        limits,
+    );
+    assert!(output.success());
+    assert!(output.truncated);
+    assert_eq!(output.stdout.len(), 100);
+    assert!(output.stdout.starts_with("line 0\nline 1\n"));
+}
@@ -0,0 +1,80 @@
+//! The COMMANDS interpreter reading a script from stdin (`cheat_sheet --script`),
+//! and how both entry points exit when it fails.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const CHEAT_SHEET: &str = env!("CARGO_BIN_EXE_cheat_sheet");
+const BOXED: &str = env!("CARGO_BIN_EXE_cheat_sheet_boxed");
+
+fn script(exe: &str, input: &str) -> Output {
+    let mut child = Command::new(exe)
+        .arg("--script")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("run cheat_sheet");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn runs_a_script_to_the_end() {
+    for exe in [CHEAT_SHEET, BOXED] {
+        let out = script(exe, "write hello\nmove 2 -1\n# done\nwrite !\n");
+        assert!(out.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout),
+            "State { x: 2, y: -1, buffer: \"hello!\", running: true }\n"
+        );
+    }
+}
+
+#[test]
+fn a_bad_line_fails_with_its_number() {
+    let out = script(CHEAT_SHEET, "move 1 1\nmove one 2\nquit\n");
+    assert_eq!(out.status.code(), Some(65));
+    assert!(out.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8_lossy(&out.stderr),
+        "cheat_sheet: line 2: `one` is not a number\n"
+    );
+}
+
+#[test]
+fn a_boxed_error_from_main_is_debug_printed_with_status_1() {
+    let out = script(BOXED, "move 1 1\nmove one 2\nquit\n");
+    assert_eq!(out.status.code(), Some(1));
+    assert!(out.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8_lossy(&out.stderr),
+        "Error: Parse { line: 2, error: BadNumber { arg: \"one\", source: ParseIntError { kind: InvalidDigit } } }\n"
+    );
+}
+
+#[test]
+fn unknown_sections_are_usage_errors() {
+    let out = Command::new(CHEAT_SHEET).arg("nope").output().unwrap();
+    assert_eq!(out.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8_lossy(&out.stderr),
+        "cheat_sheet: no section `nope`\n"
+    );
+
+    let out = Command::new(BOXED)
+        .args(["ownership", "nope"])
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&out.stdout).starts_with("borrowed: hello\n"));
+    assert_eq!(
+        String::from_utf8_lossy(&out.stderr),
+        "Error: \"no section `nope`\"\n"
+    );
+}
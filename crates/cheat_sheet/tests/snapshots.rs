@@ -0,0 +1,79 @@
+//! Golden-output tests: every section's stdout against its checked-in snapshot.
+//!
+//! Regenerate with `UPDATE_SNAPSHOTS=1 cargo test -p cheat_sheet --test snapshots`.
+
+use std::path::Path;
+use std::process::Command;
+
+use cheat_sheet::registry::{self, Section};
+use cheat_sheet::{annotations, snapshot};
+
+fn run(args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_cheat_sheet"))
+        .args(args)
+        .output()
+        .expect("run cheat_sheet");
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8(out.stdout).unwrap()
+}
+
+fn workspace_path(section: &Section) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .join(section.output_file)
+}
+
+#[test]
+fn every_section_matches_its_snapshot() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failures = Vec::new();
+    for section in registry::registry() {
+        let actual = run(&[section.id]);
+        if update {
+            std::fs::write(workspace_path(section), &actual).unwrap();
+        } else if !snapshot::matches(section, &actual) {
+            failures.push(format!(
+                "{} ({}):\n--- expected\n{}--- actual\n{}",
+                section.id, section.output_file, section.output, actual
+            ));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "output changed; rerun with UPDATE_SNAPSHOTS=1 if intended\n\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn full_run_is_every_snapshot_in_order() {
+    let expected: String = registry::registry().iter().map(|s| s.output).collect();
+    assert_eq!(
+        snapshot::normalize(&run(&[]), true),
+        snapshot::normalize(&expected, true)
+    );
+}
+
+#[test]
+fn unknown_section_fails() {
+    let out = Command::new(env!("CARGO_BIN_EXE_cheat_sheet"))
+        .arg("nope")
+        .output()
+        .unwrap();
+    assert!(!out.status.success());
+}
+
+#[test]
+fn inline_annotations_match_real_output() {
+    let mut misses = Vec::new();
+    for section in registry::registry() {
+        if let Err(m) = annotations::check(section, &run(&[section.id])) {
+            misses.extend(m.iter().map(ToString::to_string));
+        }
+    }
+    assert!(misses.is_empty(), "{}", misses.join("\n"));
+}
@@ -0,0 +1,215 @@
+//! Browser state: which sections are listed, which one is selected, and
+//! what the live runs printed. Knows nothing about terminals.
+
+use std::collections::HashMap;
+
+use cheat_sheet::registry::{self, Section, Sheet};
+
+/// What a key press asks the event loop to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    None,
+    /// Run the selected section live.
+    Run,
+    Quit,
+}
+
+/// Keys the browser understands, independent of the terminal backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Char(char),
+    Esc,
+}
+
+/// The result of running a section live.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Live {
+    Output(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct App {
+    /// `None` shows both sheets.
+    pub sheet: Option<Sheet>,
+    /// Every tag, in registry order; `tag` indexes into it.
+    pub tags: Vec<&'static str>,
+    pub tag: Option<usize>,
+    /// Index into [`visible`](Self::visible).
+    pub selected: usize,
+    /// Lines scrolled off the top of the source pane.
+    pub scroll: u16,
+    /// Live runs, by section ID.
+    pub live: HashMap<&'static str, Live>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        App::new()
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        let mut tags: Vec<&'static str> = Vec::new();
+        for s in registry::registry() {
+            for tag in s.tags {
+                if !tags.contains(tag) {
+                    tags.push(tag);
+                }
+            }
+        }
+        App {
+            sheet: None,
+            tags,
+            tag: None,
+            selected: 0,
+            scroll: 0,
+            live: HashMap::new(),
+        }
+    }
+
+    /// The sections passing the sheet and tag filters.
+    pub fn visible(&self) -> Vec<&'static Section> {
+        registry::registry()
+            .iter()
+            .filter(|s| self.sheet.is_none_or(|sheet| s.sheet == sheet))
+            .filter(|s| self.tag_filter().is_none_or(|t| s.has_tag(t)))
+            .collect()
+    }
+
+    pub fn tag_filter(&self) -> Option<&'static str> {
+        self.tag.map(|i| self.tags[i])
+    }
+
+    pub fn current(&self) -> Option<&'static Section> {
+        self.visible().get(self.selected).copied()
+    }
+
+    pub fn handle(&mut self, key: Key) -> Action {
+        let count = self.visible().len();
+        match key {
+            Key::Char('q') | Key::Esc => return Action::Quit,
+            Key::Char('r') if count > 0 => return Action::Run,
+            Key::Down | Key::Char('j') if self.selected + 1 < count => {
+                self.select(self.selected + 1)
+            }
+            Key::Up | Key::Char('k') if self.selected > 0 => self.select(self.selected - 1),
+            Key::PageDown | Key::Char(' ') => {
+                // Stop with the last source line at the top of the pane.
+                let last = self
+                    .current()
+                    .map_or(0, |s| s.source.lines().count().saturating_sub(1));
+                let last = u16::try_from(last).unwrap_or(u16::MAX);
+                self.scroll = self.scroll.saturating_add(10).min(last);
+            }
+            Key::PageUp | Key::Char('b') => self.scroll = self.scroll.saturating_sub(10),
+            Key::Char('t') => {
+                self.tag = match self.tag {
+                    None if !self.tags.is_empty() => Some(0),
+                    Some(i) if i + 1 < self.tags.len() => Some(i + 1),
+                    _ => None,
+                };
+                self.refilter();
+            }
+            Key::Char('T') => {
+                self.tag = match self.tag {
+                    None => self.tags.len().checked_sub(1),
+                    Some(0) => None,
+                    Some(i) => Some(i - 1),
+                };
+                self.refilter();
+            }
+            Key::Char('s') => {
+                self.sheet = match self.sheet {
+                    None => Some(Sheet::Basics),
+                    Some(Sheet::Basics) => Some(Sheet::Types),
+                    Some(Sheet::Types) => None,
+                };
+                self.refilter();
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    /// Stores a live run of the selected section.
+    pub fn set_live(&mut self, section: &'static Section, live: Live) {
+        self.live.insert(section.id, live);
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.scroll = 0;
+    }
+
+    fn refilter(&mut self) {
+        let count = self.visible().len();
+        self.select(self.selected.min(count.saturating_sub(1)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_within_the_list() {
+        let mut app = App::new();
+        assert_eq!(app.current().unwrap().id, "variables");
+        app.handle(Key::Up);
+        assert_eq!(app.selected, 0);
+        app.handle(Key::Down);
+        app.handle(Key::Char('j'));
+        assert_eq!(app.current().unwrap().id, "control_flow");
+        assert_eq!(app.handle(Key::Char('r')), Action::Run);
+        assert_eq!(app.handle(Key::Char('q')), Action::Quit);
+    }
+
+    #[test]
+    fn filters_by_tag_and_sheet() {
+        let mut app = App::new();
+        assert_eq!(app.visible().len(), registry::registry().len());
+        app.handle(Key::Char('t'));
+        let first = app.tag_filter().unwrap();
+        assert!(app.visible().iter().all(|s| s.has_tag(first)));
+        app.handle(Key::Char('T'));
+        assert_eq!(app.tag, None);
+        app.handle(Key::Char('T'));
+        assert_eq!(app.tag, Some(app.tags.len() - 1));
+
+        let mut app = App::new();
+        app.handle(Key::Char('s'));
+        app.handle(Key::Char('s'));
+        assert!(app.visible().iter().all(|s| s.sheet == Sheet::Types));
+        app.handle(Key::Char('s'));
+        assert_eq!(app.sheet, None);
+    }
+
+    #[test]
+    fn page_down_stops_at_the_last_line() {
+        let mut app = App::new();
+        let lines = app.current().unwrap().source.lines().count();
+        for _ in 0..lines {
+            app.handle(Key::PageDown);
+        }
+        assert_eq!(usize::from(app.scroll), lines - 1);
+        app.handle(Key::PageUp);
+        assert_eq!(usize::from(app.scroll), lines - 11);
+    }
+
+    #[test]
+    fn selection_stays_in_range_when_filtering() {
+        let mut app = App::new();
+        for _ in 0..40 {
+            app.handle(Key::Down);
+        }
+        app.handle(Key::Char('s'));
+        assert_eq!(app.selected, app.visible().len() - 1);
+        assert_eq!(app.current().unwrap().sheet, Sheet::Basics);
+    }
+}
@@ -0,0 +1,41 @@
+//! A terminal browser for the cheat sheet sections.
+//!
+//! The left pane lists the sections of both sheets (filtered by sheet and
+//! tag), the middle one shows the selected section's source with syntax
+//! highlighting, and the right one its checked-in output, or the output of a
+//! live run once `r` has been pressed.
+//!
+//! [`App`] holds the state and reacts to [`Key`]s; [`ui::draw`] renders it
+//! into any ratatui backend, so the frames can be snapshot-tested with
+//! `TestBackend`. The binary adds the terminal and the event loop.
+
+pub mod app;
+pub mod ui;
+
+use std::path::Path;
+use std::process::Command;
+
+use cheat_sheet::registry::Section;
+use cheat_sheet::runner::{self, Limits};
+
+pub use app::{Action, App, Key, Live};
+
+/// Runs `section` live by re-running `exe --run <id>`, so a panicking or
+/// looping demo can't take the terminal down with it.
+pub fn run_live(exe: &Path, section: &Section) -> Live {
+    let mut command = Command::new(exe);
+    command.args(["--run", section.id]);
+    match runner::run(&mut command, Limits::default()) {
+        Ok(out) if out.success() => Live::Output(out.stdout),
+        Ok(out) if out.timed_out() => Live::Failed(format!(
+            "stopped after {}s",
+            Limits::default().timeout.as_secs()
+        )),
+        Ok(out) => Live::Failed(format!(
+            "exited with {}\n{}",
+            out.status.expect("not timed out"),
+            out.stderr.trim()
+        )),
+        Err(e) => Live::Failed(format!("could not run `{}`: {e}", section.id)),
+    }
+}
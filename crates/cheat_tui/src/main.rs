@@ -0,0 +1,82 @@
+//! `cheat-tui`: browse the cheat sheet sections in the terminal.
+//!
+//! `cheat-tui --run <section>` runs one section's demo and exits; the
+//! browser uses it to run sections live in a child process.
+
+use std::error::Error;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use cheat_sheet::registry;
+use cheat_tui::{run_live, ui, Action, App, Key};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::DefaultTerminal;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args[..] {
+        [] => {
+            let mut terminal = ratatui::init();
+            let result = browse(&mut terminal);
+            ratatui::restore();
+            result
+        }
+        ["--run", id] => match registry::find(id) {
+            Some(section) => {
+                (section.run)();
+                Ok(())
+            }
+            None => Err(format!("no section `{id}`").into()),
+        },
+        _ => Err("usage: cheat-tui [--run <section>]".into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("cheat-tui: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn browse(terminal: &mut DefaultTerminal) -> Result<(), Box<dyn Error>> {
+    let exe = std::env::current_exe()?;
+    let mut app = App::new();
+    loop {
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        let Event::Key(press) = event::read()? else {
+            continue;
+        };
+        if press.kind != KeyEventKind::Press {
+            continue;
+        }
+        let Some(key) = key(press.code) else {
+            continue;
+        };
+        match app.handle(key) {
+            Action::Quit => return Ok(()),
+            Action::Run => {
+                if let Some(section) = app.current() {
+                    app.set_live(section, run_live(&exe, section));
+                }
+            }
+            Action::None => {}
+        }
+    }
+}
+
+fn key(code: KeyCode) -> Option<Key> {
+    Some(match code {
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Char(c) => Key::Char(c),
+        _ => return None,
+    })
+}
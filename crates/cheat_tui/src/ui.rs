@@ -0,0 +1,166 @@
+//! Draws an [`App`] into a ratatui frame: the section list, the highlighted
+//! source and the output side by side, with a title bar and key help.
+
+use cheat_sheet::export::highlight::{line_tokens, Class};
+use cheat_sheet::registry::{Section, Sheet};
+use cheat_sheet::snapshot;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::app::{App, Live};
+
+const KEYS: &str = " ↑↓ select  t/T tag  s sheet  PgUp/PgDn scroll  r run  q quit";
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [title, body, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [list, source, output] = Layout::horizontal([
+        Constraint::Percentage(25),
+        Constraint::Percentage(45),
+        Constraint::Percentage(30),
+    ])
+    .areas(body);
+
+    frame.render_widget(title_bar(app), title);
+    draw_list(frame, app, list);
+    let section = app.current();
+    frame.render_widget(source_pane(section, app.scroll), source);
+    frame.render_widget(output_pane(section, app), output);
+    frame.render_widget(
+        Paragraph::new(KEYS).style(Style::new().fg(Color::DarkGray)),
+        help,
+    );
+}
+
+fn title_bar(app: &App) -> Paragraph<'static> {
+    let sheet = match app.sheet {
+        None => "all sheets",
+        Some(Sheet::Basics) => "basics",
+        Some(Sheet::Types) => "types",
+    };
+    let tag = app.tag_filter().unwrap_or("any tag");
+    let count = match app.visible().len() {
+        1 => "1 section".to_string(),
+        n => format!("{n} sections"),
+    };
+    Paragraph::new(Line::from(vec![
+        Span::styled(" cheat ", Style::new().add_modifier(Modifier::BOLD)),
+        Span::raw(format!("│ {sheet} │ {tag} │ {count}")),
+    ]))
+    .style(Style::new().bg(Color::Blue).fg(Color::White))
+}
+
+fn draw_list(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .visible()
+        .iter()
+        .map(|s| {
+            let style = match app.live.get(s.id) {
+                Some(Live::Failed(_)) => Style::new().fg(Color::Red),
+                Some(Live::Output(_)) => Style::new().fg(Color::Green),
+                None => Style::new(),
+            };
+            ListItem::new(Line::styled(s.id, style))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::bordered().title(" sections "))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(app.current().map(|_| app.selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn source_pane(section: Option<&Section>, scroll: u16) -> Paragraph<'static> {
+    let Some(section) = section else {
+        return Paragraph::new("no section matches this filter").block(Block::bordered());
+    };
+    let width = section.source.lines().count().to_string().len();
+    let lines: Vec<Line> = section
+        .source
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut spans = vec![Span::styled(
+                format!("{:>width$} ", i + 1),
+                Style::new().fg(Color::DarkGray),
+            )];
+            spans.extend(
+                line_tokens(line)
+                    .into_iter()
+                    .map(|(class, range)| Span::styled(line[range].to_string(), style(class))),
+            );
+            Line::from(spans)
+        })
+        .collect();
+    Paragraph::new(lines)
+        .block(Block::bordered().title(format!(" {} · {} ", section.number, section.title)))
+        .scroll((scroll, 0))
+}
+
+fn style(class: Class) -> Style {
+    let s = Style::new();
+    match class {
+        Class::Comment => s.fg(Color::DarkGray),
+        Class::Attribute => s.fg(Color::Gray),
+        Class::String | Class::Char => s.fg(Color::Green),
+        Class::Lifetime => s.fg(Color::LightRed),
+        Class::Number => s.fg(Color::Cyan),
+        Class::Keyword => s.fg(Color::Magenta).add_modifier(Modifier::BOLD),
+        Class::Macro => s.fg(Color::Yellow),
+        Class::Type => s.fg(Color::LightBlue),
+        Class::Method => s.fg(Color::LightYellow),
+        Class::Ident | Class::Plain => s,
+    }
+}
+
+/// The checked-in output, or the live run once there is one, with a note
+/// when the live output differs from the snapshot.
+fn output_pane(section: Option<&Section>, app: &App) -> Paragraph<'static> {
+    let Some(section) = section else {
+        return Paragraph::new("").block(Block::bordered().title(" output "));
+    };
+    let (title, mut lines): (_, Vec<Line>) = match app.live.get(section.id) {
+        None => (
+            " output (snapshot) ",
+            section
+                .output
+                .lines()
+                .map(|l| Line::raw(l.to_string()))
+                .collect(),
+        ),
+        Some(Live::Failed(error)) => (
+            " output (run failed) ",
+            error
+                .lines()
+                .map(|l| Line::styled(l.to_string(), Style::new().fg(Color::Red)))
+                .collect(),
+        ),
+        Some(Live::Output(out)) => {
+            let mut lines: Vec<Line> = out.lines().map(|l| Line::raw(l.to_string())).collect();
+            if !snapshot::matches(section, out) {
+                lines.insert(
+                    0,
+                    Line::styled("differs from the snapshot", Style::new().fg(Color::Yellow)),
+                );
+            }
+            (" output (live) ", lines)
+        }
+    };
+    if lines.is_empty() {
+        lines.push(Line::styled(
+            "(no output)",
+            Style::new().fg(Color::DarkGray),
+        ));
+    }
+    Paragraph::new(lines)
+        .block(Block::bordered().title(title))
+        .wrap(Wrap { trim: false })
+}
@@ -0,0 +1,149 @@
+//! Rendered-frame snapshots, drawn headlessly with ratatui's `TestBackend`.
+//!
+//! Each frame is stored as plain text under `crates/cheat_tui/snapshots/`.
+//! Regenerate with `UPDATE_SNAPSHOTS=1 cargo test -p cheat_tui --test frames`.
+
+use std::path::{Path, PathBuf};
+
+use cheat_sheet::registry;
+use cheat_tui::{run_live, ui, Action, App, Key, Live};
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use ratatui::Terminal;
+
+fn render(app: &App) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(110, 32)).unwrap();
+    terminal.draw(|frame| ui::draw(frame, app)).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn text(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    let symbols: Vec<&str> = buffer.content.iter().map(|c| c.symbol()).collect();
+    symbols
+        .chunks(width)
+        .map(|row| row.concat().trim_end().to_string() + "\n")
+        .collect()
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{name}.txt"))
+}
+
+fn assert_frame(name: &str, app: &App) {
+    let actual = text(&render(app));
+    let path = snapshot_path(name);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        expected == actual,
+        "frame `{name}` changed; rerun with UPDATE_SNAPSHOTS=1 if intended\n\
+         --- expected\n{expected}--- actual\n{actual}"
+    );
+}
+
+fn press(app: &mut App, keys: &str) {
+    for c in keys.chars() {
+        assert_eq!(app.handle(Key::Char(c)), Action::None, "{c}");
+    }
+}
+
+/// Narrows the list to one tag, as pressing `t` until it shows would.
+fn filter_tag(app: &mut App, tag: &str) {
+    let index = app.tags.iter().position(|&t| t == tag).unwrap();
+    press(app, &"t".repeat(index + 1));
+}
+
+// The golden frames below filter the list down first, so adding a section
+// elsewhere in the registry doesn't change them. The unfiltered first frame
+// is checked pane by pane instead.
+#[test]
+fn first_frame_lists_both_sheets() {
+    let app = App::new();
+    let frame = text(&render(&app));
+    let header = format!(
+        " cheat │ all sheets │ any tag │ {} sections\n",
+        registry::registry().len()
+    );
+    assert!(frame.starts_with(&header), "{frame}");
+    assert!(frame.contains("┌ 1 · Variables + Types ─"), "{frame}");
+    assert!(frame.contains("│> variables "), "{frame}");
+    assert!(frame.contains("│  types/primitives "), "{frame}");
+    assert!(frame.contains("┌ output (snapshot) ─"), "{frame}");
+    assert!(
+        frame.contains("││x=5, y=11, MAX=99, big=1000000 │"),
+        "{frame}"
+    );
+}
+
+#[test]
+fn tag_filter_narrows_the_list() {
+    let mut app = App::new();
+    filter_tag(&mut app, "HASHMAP");
+    press(&mut app, "j");
+    assert_eq!(app.current().unwrap().id, "types/hashmap");
+    assert_frame("tag_hashmap", &app);
+}
+
+#[test]
+fn types_sheet_scrolls_the_source() {
+    let mut app = App::new();
+    press(&mut app, "ss");
+    filter_tag(&mut app, "BORROWING");
+    app.handle(Key::PageDown);
+    assert_eq!(app.visible().len(), 1);
+    assert_eq!(app.current().unwrap().id, "types/references");
+    assert_frame("types_scrolled", &app);
+}
+
+#[test]
+fn source_is_highlighted() {
+    let app = App::new();
+    let buffer = render(&app);
+    let frame = text(&buffer);
+    // The first `fn` in the source pane is a bold keyword.
+    let (row, line) = frame
+        .lines()
+        .enumerate()
+        .find(|(_, l)| l.contains(" pub fn run()"))
+        .unwrap();
+    let col = line.find("fn run").unwrap();
+    let col = line[..col].chars().count() as u16;
+    let cell = &buffer[(col, row as u16)];
+    assert_eq!(cell.fg, Color::Magenta);
+    assert!(cell.modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn live_run_replaces_the_snapshot() {
+    let mut app = App::new();
+    filter_tag(&mut app, "OWNERSHIP");
+    assert_eq!(app.handle(Key::Char('r')), Action::Run);
+    let section = app.current().unwrap();
+    assert_eq!(section.id, "ownership");
+    let live = run_live(Path::new(env!("CARGO_BIN_EXE_cheat-tui")), section);
+    assert_eq!(live, Live::Output(section.output.to_string()));
+    app.set_live(section, live);
+    assert_frame("live_run", &app);
+
+    app.set_live(section, Live::Output("something else\n".into()));
+    assert!(text(&render(&app)).contains("differs from the snapshot"));
+}
+
+#[test]
+fn failed_run_shows_the_error() {
+    let mut app = App::new();
+    let section = registry::find("vec").unwrap();
+    let live = run_live(Path::new("/nonexistent/cheat-tui"), section);
+    assert!(matches!(&live, Live::Failed(e) if e.starts_with("could not run `vec`")));
+    press(&mut app, "jjjjj");
+    app.set_live(section, live);
+    assert!(text(&render(&app)).contains("output (run failed)"));
+}